@@ -0,0 +1,293 @@
+use crate::context::CliContext;
+use anchor_lang::{Discriminator, Space};
+use anyhow::{ensure, Result};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{native_token::lamports_to_sol, pubkey::Pubkey};
+use solana_stablecoin::{state::PositionCounter, ANCHOR_DISCRIMINATOR};
+use solana_stablecoin_client::{
+    self as client, decode_account, decode_collateral, find_position_counter_address,
+    health_factor, AmountMode, Collateral, PositionAddresses,
+};
+
+pub fn init(
+    context: &CliContext,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+    recovery_authority: Pubkey,
+    legacy: bool,
+) -> Result<()> {
+    let instruction = if legacy {
+        client::initialize_config_legacy(&context.wallet(), recovery_authority)
+    } else {
+        // clap only lets these be missing together with --legacy
+        client::initialize_config(
+            &context.wallet(),
+            name.unwrap_or_default(),
+            symbol.unwrap_or_default(),
+            uri.unwrap_or_default(),
+            recovery_authority,
+        )
+    };
+    let signature = context.send(&[instruction])?;
+    println!("Initialized config: {signature}");
+    Ok(())
+}
+
+pub fn show_config(context: &CliContext) -> Result<()> {
+    let config = context.fetch_config()?;
+    println!("Authority:             {}", config.authority);
+    println!("Mint:                  {}", config.mint_account);
+    println!("Token program:         {}", config.token_program);
+    println!("Compliance authority:  {}", config.compliance_authority);
+    println!("Recovery authority:    {}", config.recovery_authority);
+    println!("Recovery timelock:     {}s", config.recovery_timelock);
+    println!("Liquidation threshold: {}%", config.liquidation_threshold);
+    println!("Liquidation bonus:     {}%", config.liquidation_bonus);
+    println!("Min health factor:     {}", config.min_health_factor);
+    println!("Auction premium:       {} bps", config.auction_premium_bps);
+    println!("Auction floor:         {} bps", config.auction_floor_bps);
+    println!("Auction curve:         {:?}", config.auction_curve);
+    println!("Keeper tip:            {} lamports", config.keeper_tip);
+    Ok(())
+}
+
+pub fn update_config(context: &CliContext, min_health_factor: u64) -> Result<()> {
+    let signature = context.send(&[client::update_config(&context.wallet(), min_health_factor)])?;
+    println!("Updated config: {signature}");
+    Ok(())
+}
+
+pub fn sweep_donations(context: &CliContext, creator: &Pubkey, position_id: u64) -> Result<()> {
+    let position = PositionAddresses::new(creator, position_id);
+    let signature = context.send(&[client::sweep_donations(&context.wallet(), &position)])?;
+    println!("Swept donations from position {creator}/{position_id}: {signature}");
+    Ok(())
+}
+
+/// Deposits into `position_id`, or opens the wallet's next position in the same
+/// transaction when no id is given.
+pub fn deposit_mint(
+    context: &CliContext,
+    creator: Pubkey,
+    position_id: Option<u64>,
+    price_update: &Pubkey,
+    amount_collateral: u64,
+    amount_to_mint: AmountMode,
+) -> Result<()> {
+    let config = context.fetch_config()?;
+    let wallet = context.wallet();
+    let mut instructions = Vec::new();
+    let position_id = match position_id {
+        Some(position_id) => position_id,
+        None => {
+            let position_id = next_position_id(context, &wallet)?;
+            instructions.push(client::open_position(
+                &wallet,
+                position_id,
+                &config.token_program,
+            ));
+            position_id
+        }
+    };
+    let position = PositionAddresses::new(&creator, position_id);
+    instructions.push(client::deposit_collateral_and_mint_tokens(
+        &wallet,
+        &position,
+        price_update,
+        &config.token_program,
+        amount_collateral,
+        amount_to_mint,
+    ));
+    let signature = context.send(&instructions)?;
+    println!("Deposited into position {creator}/{position_id}: {signature}");
+    Ok(())
+}
+
+pub fn redeem_burn(
+    context: &CliContext,
+    creator: &Pubkey,
+    position_id: u64,
+    price_update: &Pubkey,
+    amount_collateral: AmountMode,
+    amount_to_burn: u64,
+) -> Result<()> {
+    let config = context.fetch_config()?;
+    let position = PositionAddresses::new(creator, position_id);
+    let signature = context.send(&[client::redeem_collateral_and_burn_tokens(
+        &context.wallet(),
+        &position,
+        price_update,
+        &config.token_program,
+        amount_collateral,
+        amount_to_burn,
+    )])?;
+    println!("Redeemed from position {creator}/{position_id}: {signature}");
+    Ok(())
+}
+
+pub fn liquidate(
+    context: &CliContext,
+    creator: &Pubkey,
+    position_id: u64,
+    price_update: &Pubkey,
+    amount_to_burn: u64,
+) -> Result<()> {
+    let config = context.fetch_config()?;
+    let position = PositionAddresses::new(creator, position_id);
+    let collateral = context.fetch_collateral(&position.collateral_account)?;
+    let price = context.fetch_price(price_update)?;
+    ensure!(
+        client::is_liquidatable(&collateral, &config, &price)?,
+        "position {creator}/{position_id} is above the minimum health factor"
+    );
+
+    let signature = context.send(&[client::liquidate(
+        &context.wallet(),
+        &position,
+        price_update,
+        &config.token_program,
+        amount_to_burn,
+    )])?;
+    println!("Liquidated position {creator}/{position_id}: {signature}");
+    Ok(())
+}
+
+pub fn start_auction(
+    context: &CliContext,
+    creator: &Pubkey,
+    position_id: u64,
+    price_update: &Pubkey,
+) -> Result<()> {
+    let position = PositionAddresses::new(creator, position_id);
+    let signature = context.send(&[client::start_liquidation_auction(
+        &context.wallet(),
+        &position,
+        price_update,
+    )])?;
+    println!("Started auction for position {creator}/{position_id}: {signature}");
+    Ok(())
+}
+
+pub fn bid(
+    context: &CliContext,
+    creator: &Pubkey,
+    position_id: u64,
+    amount_to_burn: u64,
+) -> Result<()> {
+    let config = context.fetch_config()?;
+    let position = PositionAddresses::new(creator, position_id);
+    let auction = context.fetch_auction(&position.auction)?;
+    let signature = context.send(&[client::bid(
+        &context.wallet(),
+        &position,
+        &auction.starter,
+        &config.token_program,
+        amount_to_burn,
+    )])?;
+    println!("Bid on position {creator}/{position_id}: {signature}");
+    Ok(())
+}
+
+pub fn show_position(
+    context: &CliContext,
+    creator: &Pubkey,
+    position_id: u64,
+    price_update: Option<&Pubkey>,
+) -> Result<()> {
+    let position = PositionAddresses::new(creator, position_id);
+    let collateral = context.fetch_collateral(&position.collateral_account)?;
+    println!("Address:        {}", position.collateral_account);
+    println!("Creator:        {}", collateral.creator);
+    println!("Position id:    {}", collateral.position_id);
+    println!("Last depositor: {}", collateral.depositor);
+    println!("Position NFT:   {}", collateral.position_mint);
+    println!(
+        "Collateral:     {} SOL",
+        lamports_to_sol(collateral.lamport_balance)
+    );
+    println!("Minted:         {}", collateral.amount_minted);
+    if let Some(price_update) = price_update {
+        let config = context.fetch_config()?;
+        let price = context.fetch_price(price_update)?;
+        println!(
+            "Health factor:  {}",
+            format_health_factor(health_factor(&collateral, &config, &price)?)
+        );
+    }
+    Ok(())
+}
+
+pub fn list_positions(
+    context: &CliContext,
+    price_update: Option<&Pubkey>,
+    unhealthy_only: bool,
+) -> Result<()> {
+    let config = context.fetch_config()?;
+    let price = price_update
+        .map(|price_update| context.fetch_price(price_update))
+        .transpose()?;
+
+    for (address, collateral) in fetch_positions(context)? {
+        let health = price
+            .as_ref()
+            .map(|price| health_factor(&collateral, &config, price))
+            .transpose()?;
+        if unhealthy_only && health.is_none_or(|health| health >= config.min_health_factor) {
+            continue;
+        }
+        println!(
+            "{address}  creator {} id {}  collateral {} SOL  minted {}{}",
+            collateral.creator,
+            collateral.position_id,
+            lamports_to_sol(collateral.lamport_balance),
+            collateral.amount_minted,
+            health
+                .map(|health| format!("  health {}", format_health_factor(health)))
+                .unwrap_or_default(),
+        );
+    }
+    Ok(())
+}
+
+fn next_position_id(context: &CliContext, depositor: &Pubkey) -> Result<u64> {
+    let address = find_position_counter_address(depositor).0;
+    match context.rpc.get_account_data(&address) {
+        Ok(data) => Ok(decode_account::<PositionCounter>(&data)?.next_position_id),
+        // the counter is created with the first position
+        Err(_) => Ok(0),
+    }
+}
+
+fn fetch_positions(context: &CliContext) -> Result<Vec<(Pubkey, Collateral)>> {
+    let accounts = context.rpc.get_program_accounts_with_config(
+        &solana_stablecoin::ID,
+        RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize((ANCHOR_DISCRIMINATOR + Collateral::INIT_SPACE) as u64),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Collateral::DISCRIMINATOR.to_vec())),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        },
+    )?;
+    accounts
+        .into_iter()
+        .map(|(address, account)| Ok((address, decode_collateral(&account.data)?)))
+        .collect()
+}
+
+fn format_health_factor(health_factor: u64) -> String {
+    if health_factor == u64::MAX {
+        "no debt".to_string()
+    } else {
+        health_factor.to_string()
+    }
+}
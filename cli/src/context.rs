@@ -0,0 +1,100 @@
+use anyhow::{anyhow, Context, Result};
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+use solana_clap_utils::input_validators::normalize_to_url_if_moniker;
+use solana_cli_config::{Config as SolanaCliConfig, CONFIG_FILE};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    account::from_account,
+    clock::Clock,
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signature, Signer},
+    sysvar,
+    transaction::Transaction,
+};
+use solana_stablecoin_client::{
+    decode_account, decode_auction, decode_collateral, decode_config, find_config_address,
+    sol_usd_price, Auction, Collateral, Config,
+};
+use std::str::FromStr;
+
+/// RPC connection and signer resolved from the Solana CLI config and the global flags.
+pub struct CliContext {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+}
+
+impl CliContext {
+    pub fn load(
+        config_file: Option<&str>,
+        url: Option<String>,
+        keypair: Option<String>,
+    ) -> Result<Self> {
+        let config = match config_file.or(CONFIG_FILE.as_deref()) {
+            Some(path) => SolanaCliConfig::load(path).unwrap_or_default(),
+            None => SolanaCliConfig::default(),
+        };
+        let json_rpc_url = normalize_to_url_if_moniker(url.unwrap_or(config.json_rpc_url));
+        let keypair_path = keypair.unwrap_or(config.keypair_path);
+        let payer = read_keypair_file(&keypair_path)
+            .map_err(|e| anyhow!("failed to read keypair {keypair_path}: {e}"))?;
+        let commitment =
+            CommitmentConfig::from_str(&config.commitment).unwrap_or(CommitmentConfig::confirmed());
+        Ok(Self {
+            rpc: RpcClient::new_with_commitment(json_rpc_url, commitment),
+            payer,
+        })
+    }
+
+    pub fn wallet(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    pub fn send(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+        Ok(self
+            .rpc
+            .send_and_confirm_transaction_with_spinner(&transaction)?)
+    }
+
+    pub fn fetch_config(&self) -> Result<Config> {
+        let data = self
+            .rpc
+            .get_account_data(&find_config_address().0)
+            .context("config account not found, run `stablecoin init` first")?;
+        Ok(decode_config(&data)?)
+    }
+
+    pub fn fetch_collateral(&self, address: &Pubkey) -> Result<Collateral> {
+        let data = self
+            .rpc
+            .get_account_data(address)
+            .with_context(|| format!("position {address} not found"))?;
+        Ok(decode_collateral(&data)?)
+    }
+
+    pub fn fetch_auction(&self, address: &Pubkey) -> Result<Auction> {
+        let data = self
+            .rpc
+            .get_account_data(address)
+            .with_context(|| format!("no auction at {address}"))?;
+        Ok(decode_auction(&data)?)
+    }
+
+    /// The price the program would read from `price_update` right now, so stale
+    /// updates are rejected here too.
+    pub fn fetch_price(&self, price_update: &Pubkey) -> Result<Price> {
+        let data = self.rpc.get_account_data(price_update)?;
+        let price_update: PriceUpdateV2 = decode_account(&data)?;
+        let clock: Clock = from_account(&self.rpc.get_account(&sysvar::clock::ID)?)
+            .context("failed to decode the clock sysvar")?;
+        Ok(sol_usd_price(&price_update, clock.unix_timestamp)?)
+    }
+}
@@ -0,0 +1,308 @@
+//! `stablecoin`: operate the stablecoin program from the command line.
+//!
+//! The RPC URL, keypair and commitment come from the Solana CLI config, so the tool
+//! follows `solana config set` like the rest of the Solana tooling.
+mod commands;
+mod context;
+
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand};
+use context::CliContext;
+use solana_sdk::pubkey::Pubkey;
+use solana_stablecoin_client::AmountMode;
+
+#[derive(Parser)]
+#[command(name = "stablecoin", version, about)]
+struct Cli {
+    /// Solana CLI config file, defaults to ~/.config/solana/cli/config.yml
+    #[arg(long, short = 'C', global = true)]
+    config: Option<String>,
+
+    /// RPC URL or moniker, overrides the Solana CLI config
+    #[arg(long, short = 'u', global = true)]
+    url: Option<String>,
+
+    /// Keypair that signs and pays, overrides the Solana CLI config
+    #[arg(long, short = 'k', global = true)]
+    keypair: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the config and the stablecoin mint
+    Init {
+        #[arg(long, required_unless_present = "legacy")]
+        name: Option<String>,
+        #[arg(long, required_unless_present = "legacy")]
+        symbol: Option<String>,
+        #[arg(long, required_unless_present = "legacy")]
+        uri: Option<String>,
+        /// Key allowed to schedule and execute recoveries, must differ from the wallet
+        #[arg(long)]
+        recovery_authority: Pubkey,
+        /// Use a classic SPL Token mint without metadata or transfer hook
+        #[arg(long)]
+        legacy: bool,
+    },
+    /// Print the protocol config
+    ShowConfig,
+    /// Change the minimum health factor
+    UpdateConfig {
+        #[arg(long)]
+        min_health_factor: u64,
+    },
+    /// Send lamports sent straight to a position's SOL vault to the authority
+    SweepDonations {
+        #[command(flatten)]
+        position: PositionArgs,
+    },
+    /// Deposit SOL into a position and mint stablecoin against it
+    DepositMint {
+        /// Position to deposit into, a new one is opened when omitted
+        #[arg(long)]
+        position_id: Option<u64>,
+        /// Depositor that opened the position, defaults to the keypair
+        #[arg(long, requires = "position_id")]
+        creator: Option<Pubkey>,
+        #[command(flatten)]
+        price: PriceArgs,
+        /// Collateral to deposit, in lamports
+        #[arg(long)]
+        collateral: u64,
+        #[command(flatten)]
+        amount: MintAmountArgs,
+    },
+    /// Burn stablecoin and take SOL out of a position
+    RedeemBurn {
+        #[command(flatten)]
+        position: PositionArgs,
+        #[command(flatten)]
+        price: PriceArgs,
+        #[command(flatten)]
+        collateral: WithdrawAmountArgs,
+        /// Stablecoin to burn, in base units
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Repay debt of an unhealthy position in exchange for its collateral
+    Liquidate {
+        #[command(flatten)]
+        position: PositionArgs,
+        #[command(flatten)]
+        price: PriceArgs,
+        /// Stablecoin to burn, in base units
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Seize an unhealthy position's collateral into a Dutch auction
+    StartAuction {
+        #[command(flatten)]
+        position: PositionArgs,
+        #[command(flatten)]
+        price: PriceArgs,
+    },
+    /// Buy collateral from a position's auction at its current price
+    Bid {
+        #[command(flatten)]
+        position: PositionArgs,
+        /// Most stablecoin to burn, in base units
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Inspect a single position
+    Position {
+        #[command(subcommand)]
+        command: PositionCommand,
+    },
+    /// Inspect every position
+    Positions {
+        #[command(subcommand)]
+        command: PositionsCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum PositionCommand {
+    /// Print a position and, given a price, its health factor
+    Show {
+        #[command(flatten)]
+        position: PositionArgs,
+        /// Pyth SOL/USD price update account
+        #[arg(long)]
+        price_update: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand)]
+enum PositionsCommand {
+    /// List positions, optionally only the ones that can be liquidated
+    List {
+        /// Only list positions below the minimum health factor
+        #[arg(long, requires = "price_update")]
+        unhealthy: bool,
+        /// Pyth SOL/USD price update account
+        #[arg(long)]
+        price_update: Option<Pubkey>,
+    },
+}
+
+#[derive(Args)]
+struct PositionArgs {
+    /// Depositor that opened the position, defaults to the keypair
+    #[arg(long)]
+    creator: Option<Pubkey>,
+    #[arg(long)]
+    position_id: u64,
+}
+
+#[derive(Args)]
+struct PriceArgs {
+    /// Pyth SOL/USD price update account
+    #[arg(long)]
+    price_update: Pubkey,
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct MintAmountArgs {
+    /// Stablecoin to mint, in base units
+    #[arg(long)]
+    amount: Option<u64>,
+    /// Mint the most that keeps the minimum health factor, less a buffer in basis points
+    #[arg(long, value_name = "BUFFER_BPS")]
+    max_safe: Option<u16>,
+    /// Mint the most that keeps the minimum health factor
+    #[arg(long)]
+    max: bool,
+}
+
+impl MintAmountArgs {
+    fn mode(&self) -> AmountMode {
+        match (self.amount, self.max_safe) {
+            (Some(amount), _) => AmountMode::Exact { amount },
+            (None, Some(buffer_bps)) => AmountMode::MaxSafe { buffer_bps },
+            (None, None) => AmountMode::All,
+        }
+    }
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct WithdrawAmountArgs {
+    /// Collateral to withdraw, in lamports
+    #[arg(long)]
+    collateral: Option<u64>,
+    /// Withdraw all collateral above the minimum health factor plus a buffer in basis points
+    #[arg(long, value_name = "BUFFER_BPS")]
+    max_safe: Option<u16>,
+    /// Withdraw all collateral, only possible once the debt is repaid
+    #[arg(long)]
+    all: bool,
+}
+
+impl WithdrawAmountArgs {
+    fn mode(&self) -> AmountMode {
+        match (self.collateral, self.max_safe) {
+            (Some(amount), _) => AmountMode::Exact { amount },
+            (None, Some(buffer_bps)) => AmountMode::MaxSafe { buffer_bps },
+            (None, None) => AmountMode::All,
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let context = CliContext::load(cli.config.as_deref(), cli.url, cli.keypair)?;
+    let wallet = context.wallet();
+
+    match cli.command {
+        Command::Init {
+            name,
+            symbol,
+            uri,
+            recovery_authority,
+            legacy,
+        } => commands::init(&context, name, symbol, uri, recovery_authority, legacy),
+        Command::ShowConfig => commands::show_config(&context),
+        Command::UpdateConfig { min_health_factor } => {
+            commands::update_config(&context, min_health_factor)
+        }
+        Command::SweepDonations { position } => commands::sweep_donations(
+            &context,
+            &position.creator.unwrap_or(wallet),
+            position.position_id,
+        ),
+        Command::DepositMint {
+            position_id,
+            creator,
+            price,
+            collateral,
+            amount,
+        } => commands::deposit_mint(
+            &context,
+            creator.unwrap_or(wallet),
+            position_id,
+            &price.price_update,
+            collateral,
+            amount.mode(),
+        ),
+        Command::RedeemBurn {
+            position,
+            price,
+            collateral,
+            amount,
+        } => commands::redeem_burn(
+            &context,
+            &position.creator.unwrap_or(wallet),
+            position.position_id,
+            &price.price_update,
+            collateral.mode(),
+            amount,
+        ),
+        Command::Liquidate {
+            position,
+            price,
+            amount,
+        } => commands::liquidate(
+            &context,
+            &position.creator.unwrap_or(wallet),
+            position.position_id,
+            &price.price_update,
+            amount,
+        ),
+        Command::StartAuction { position, price } => commands::start_auction(
+            &context,
+            &position.creator.unwrap_or(wallet),
+            position.position_id,
+            &price.price_update,
+        ),
+        Command::Bid { position, amount } => commands::bid(
+            &context,
+            &position.creator.unwrap_or(wallet),
+            position.position_id,
+            amount,
+        ),
+        Command::Position {
+            command:
+                PositionCommand::Show {
+                    position,
+                    price_update,
+                },
+        } => commands::show_position(
+            &context,
+            &position.creator.unwrap_or(wallet),
+            position.position_id,
+            price_update.as_ref(),
+        ),
+        Command::Positions {
+            command:
+                PositionsCommand::List {
+                    unhealthy,
+                    price_update,
+                },
+        } => commands::list_positions(&context, price_update.as_ref(), unhealthy),
+    }
+}
@@ -0,0 +1,30 @@
+//! Decoding of the program accounts fetched over RPC and of view instruction results.
+use anchor_lang::prelude::*;
+use anchor_lang::AccountDeserialize;
+
+pub use solana_stablecoin::{
+    state::{Auction, Collateral, Config},
+    LiquidationPrice, PositionHealth,
+};
+
+/// Decodes raw account data, checking the discriminator first.
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_config(data: &[u8]) -> Result<Config> {
+    decode_account(data)
+}
+
+pub fn decode_collateral(data: &[u8]) -> Result<Collateral> {
+    decode_account(data)
+}
+
+pub fn decode_auction(data: &[u8]) -> Result<Auction> {
+    decode_account(data)
+}
+
+/// Decodes the return data of a simulated view instruction, e.g. `PositionHealth`.
+pub fn decode_return_data<T: AnchorDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_from_slice(data).map_err(|_| ErrorCode::InstructionDidNotDeserialize.into())
+}
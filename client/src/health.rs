@@ -0,0 +1,50 @@
+//! Health-factor helpers over decoded accounts.
+//!
+//! The on-chain instructions read the price from a `PriceUpdateV2` account and the clock
+//! sysvar; here the price is passed in. Both call `solana_stablecoin::math`, so the
+//! results, rounding included, are the same.
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, Price, PriceUpdateV2};
+use solana_stablecoin::{
+    math::{self, OraclePrice},
+    Collateral, Config, MAXIMUM_AGE, SOL_USD_FEED_ID,
+};
+
+/// The SOL/USD price the program would accept from `price_update` at `unix_timestamp`.
+pub fn sol_usd_price(price_update: &PriceUpdateV2, unix_timestamp: i64) -> Result<Price> {
+    let feed_id = get_feed_id_from_hex(SOL_USD_FEED_ID)?;
+    let clock = Clock {
+        unix_timestamp,
+        ..Clock::default()
+    };
+    Ok(price_update.get_price_no_older_than(&clock, MAXIMUM_AGE, &feed_id)?)
+}
+
+pub fn usd_value(amount_in_lamports: u64, price: &Price) -> Result<u64> {
+    math::usd_value(
+        amount_in_lamports,
+        OraclePrice::new(price.price, price.exponent),
+    )
+}
+
+pub fn lamports_from_usd(amount_in_usd: u64, price: &Price) -> Result<u64> {
+    math::lamports_from_usd(amount_in_usd, OraclePrice::new(price.price, price.exponent))
+}
+
+/// Health factor of `collateral`, `u64::MAX` when nothing has been minted.
+pub fn health_factor(collateral: &Collateral, config: &Config, price: &Price) -> Result<u64> {
+    math::health_factor(
+        collateral.lamport_balance,
+        collateral.amount_minted,
+        config.liquidation_threshold,
+        OraclePrice::new(price.price, price.exponent),
+    )
+}
+
+/// Whether `liquidate` would accept the position at this price.
+pub fn is_liquidatable(collateral: &Collateral, config: &Config, price: &Price) -> Result<bool> {
+    Ok(math::is_liquidatable(
+        health_factor(collateral, config, price)?,
+        config.min_health_factor,
+    ))
+}
@@ -0,0 +1,842 @@
+//! One builder per instruction in `solana_stablecoin`.
+//!
+//! Builders take the signers, the few keys that can't be derived and the instruction
+//! arguments; every PDA and associated token account is derived here. `token_program` is
+//! the program stored in `Config::token_program`. Instructions that move the stablecoin
+//! append the transfer-hook accounts when the mint is a Token-2022 mint.
+use crate::pda::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData};
+use anchor_spl::{associated_token, token_2022};
+use solana_stablecoin::{
+    accounts as ix_accounts, instruction as ix_data, AmountMode, AuctionCurve,
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: solana_stablecoin::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn with_transfer_hook_accounts(
+    mut instruction: Instruction,
+    token_program: &Pubkey,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+) -> Instruction {
+    if *token_program == token_2022::ID {
+        instruction
+            .accounts
+            .extend(transfer_hook_accounts(source_owner, destination_owner));
+    }
+    instruction
+}
+
+// ============================================================================
+// Admin
+// ============================================================================
+
+pub fn initialize_config(
+    authority: &Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    recovery_authority: Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::InitializeConfig {
+            authority: *authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            transfer_hook_program: solana_stablecoin::TRANSFER_HOOK_PROGRAM_ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        ix_data::InitializeConfig {
+            name,
+            symbol,
+            uri,
+            recovery_authority,
+        },
+    )
+}
+
+pub fn initialize_config_legacy(authority: &Pubkey, recovery_authority: Pubkey) -> Instruction {
+    build(
+        ix_accounts::InitializeConfigLegacy {
+            authority: *authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        ix_data::InitializeConfigLegacy { recovery_authority },
+    )
+}
+
+pub fn update_config(authority: &Pubkey, min_health_factor: u64) -> Instruction {
+    build(
+        ix_accounts::UpdateConfig {
+            authority: *authority,
+            config_account: find_config_address().0,
+        },
+        ix_data::UpdateConfig { min_health_factor },
+    )
+}
+
+pub fn set_compliance_authority(authority: &Pubkey, compliance_authority: Pubkey) -> Instruction {
+    build(
+        ix_accounts::SetComplianceAuthority {
+            authority: *authority,
+            config_account: find_config_address().0,
+        },
+        ix_data::SetComplianceAuthority {
+            compliance_authority,
+        },
+    )
+}
+
+pub fn set_recovery_authority(
+    authority: &Pubkey,
+    recovery_authority: Pubkey,
+    recovery_timelock: i64,
+) -> Instruction {
+    build(
+        ix_accounts::SetRecoveryAuthority {
+            authority: *authority,
+            config_account: find_config_address().0,
+        },
+        ix_data::SetRecoveryAuthority {
+            recovery_authority,
+            recovery_timelock,
+        },
+    )
+}
+
+pub fn update_token_metadata(
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    build(
+        ix_accounts::UpdateTokenMetadata {
+            authority: *authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        ix_data::UpdateTokenMetadata { name, symbol, uri },
+    )
+}
+
+pub fn set_auction_parameters(
+    authority: &Pubkey,
+    premium_bps: u64,
+    floor_bps: u64,
+    curve: AuctionCurve,
+) -> Instruction {
+    build(
+        ix_accounts::SetAuctionParameters {
+            authority: *authority,
+            config_account: find_config_address().0,
+        },
+        ix_data::SetAuctionParameters {
+            premium_bps,
+            floor_bps,
+            curve,
+        },
+    )
+}
+
+pub fn set_keeper_tip(authority: &Pubkey, keeper_tip: u64) -> Instruction {
+    build(
+        ix_accounts::SetKeeperTip {
+            authority: *authority,
+            config_account: find_config_address().0,
+        },
+        ix_data::SetKeeperTip { keeper_tip },
+    )
+}
+
+pub fn sweep_donations(authority: &Pubkey, position: &PositionAddresses) -> Instruction {
+    build(
+        ix_accounts::SweepDonations {
+            authority: *authority,
+            config_account: find_config_address().0,
+            collateral_account: position.collateral_account,
+            sol_account: position.sol_account,
+            system_program: system_program::ID,
+        },
+        ix_data::SweepDonations {},
+    )
+}
+
+// ============================================================================
+// Compliance
+// ============================================================================
+
+pub fn add_to_blocklist(compliance_authority: &Pubkey, address: Pubkey) -> Instruction {
+    build(
+        ix_accounts::AddToBlocklist {
+            compliance_authority: *compliance_authority,
+            config_account: find_config_address().0,
+            blocklist_entry: find_blocklist_address(&address).0,
+            system_program: system_program::ID,
+        },
+        ix_data::AddToBlocklist { address },
+    )
+}
+
+pub fn remove_from_blocklist(compliance_authority: &Pubkey, address: &Pubkey) -> Instruction {
+    build(
+        ix_accounts::RemoveFromBlocklist {
+            compliance_authority: *compliance_authority,
+            config_account: find_config_address().0,
+            blocklist_entry: find_blocklist_address(address).0,
+        },
+        ix_data::RemoveFromBlocklist {},
+    )
+}
+
+pub fn freeze_account(
+    compliance_authority: &Pubkey,
+    token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::FreezeTokenAccount {
+            compliance_authority: *compliance_authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            token_account: *token_account,
+            token_program: *token_program,
+        },
+        ix_data::FreezeAccount {},
+    )
+}
+
+pub fn thaw_account(
+    compliance_authority: &Pubkey,
+    token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::ThawTokenAccount {
+            compliance_authority: *compliance_authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            token_account: *token_account,
+            token_program: *token_program,
+        },
+        ix_data::ThawAccount {},
+    )
+}
+
+// ============================================================================
+// Positions
+// ============================================================================
+
+pub fn open_position(depositor: &Pubkey, position_id: u64, token_program: &Pubkey) -> Instruction {
+    let position = PositionAddresses::new(depositor, position_id);
+    build(
+        ix_accounts::OpenPosition {
+            depositor: *depositor,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            position_counter: find_position_counter_address(depositor).0,
+            collateral_account: position.collateral_account,
+            sol_account: position.sol_account,
+            token_account: stablecoin_token_address(depositor, token_program),
+            position_mint: position.position_mint,
+            position_token_account: position.position_token_account(depositor),
+            token_program: *token_program,
+            position_token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            depositor_blocklist_entry: find_blocklist_address(depositor).0,
+        },
+        ix_data::OpenPosition { position_id },
+    )
+}
+
+/// `depositor` is the current holder of the position NFT, not necessarily its creator.
+pub fn deposit_collateral_and_mint_tokens(
+    depositor: &Pubkey,
+    position: &PositionAddresses,
+    price_update: &Pubkey,
+    token_program: &Pubkey,
+    amount_collateral: u64,
+    amount_to_mint: AmountMode,
+) -> Instruction {
+    build(
+        ix_accounts::DepositCollateralAndMintTokens {
+            mint_account: find_mint_address().0,
+            depositor: *depositor,
+            config_account: find_config_address().0,
+            collateral_account: position.collateral_account,
+            position_token_account: position.position_token_account(depositor),
+            sol_account: position.sol_account,
+            token_account: stablecoin_token_address(depositor, token_program),
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            price_update: *price_update,
+            depositor_blocklist_entry: find_blocklist_address(depositor).0,
+            auction: position.auction,
+        },
+        ix_data::DepositCollateralAndMintTokens {
+            amount_collateral,
+            amount_to_mint,
+        },
+    )
+}
+
+pub fn redeem_collateral_and_burn_tokens(
+    depositor: &Pubkey,
+    position: &PositionAddresses,
+    price_update: &Pubkey,
+    token_program: &Pubkey,
+    amount_collateral: AmountMode,
+    amount_to_burn: u64,
+) -> Instruction {
+    build(
+        ix_accounts::RedeemCollateralAndBurnTokens {
+            depositor: *depositor,
+            price_update: *price_update,
+            config_account: find_config_address().0,
+            collateral_account: position.collateral_account,
+            position_token_account: position.position_token_account(depositor),
+            sol_account: position.sol_account,
+            mint_account: find_mint_address().0,
+            token_account: stablecoin_token_address(depositor, token_program),
+            system_program: system_program::ID,
+            token_program: *token_program,
+            depositor_blocklist_entry: find_blocklist_address(depositor).0,
+            auction: position.auction,
+        },
+        ix_data::RedeemCollateralAndBurnTokens {
+            amount_collateral,
+            amount_to_burn,
+        },
+    )
+}
+
+pub fn close_position(
+    depositor: &Pubkey,
+    position: &PositionAddresses,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::ClosePosition {
+            depositor: *depositor,
+            config_account: find_config_address().0,
+            collateral_account: position.collateral_account,
+            position_token_account: position.position_token_account(depositor),
+            position_mint: position.position_mint,
+            sol_account: position.sol_account,
+            token_program: *token_program,
+            position_token_program: token_2022::ID,
+            system_program: system_program::ID,
+            depositor_blocklist_entry: find_blocklist_address(depositor).0,
+            auction: position.auction,
+        },
+        ix_data::ClosePosition {},
+    )
+}
+
+/// `new_depositor` may then accept the position, `Pubkey::default()` cancels the proposal.
+pub fn propose_position_transfer(
+    depositor: &Pubkey,
+    position: &PositionAddresses,
+    new_depositor: Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::ProposePositionTransfer {
+            depositor: *depositor,
+            collateral_account: position.collateral_account,
+            position_token_account: position.position_token_account(depositor),
+            token_program: token_2022::ID,
+            depositor_blocklist_entry: find_blocklist_address(depositor).0,
+        },
+        ix_data::ProposePositionTransfer { new_depositor },
+    )
+}
+
+/// `depositor` is the holder that proposed the transfer.
+pub fn accept_position_transfer(
+    new_depositor: &Pubkey,
+    depositor: &Pubkey,
+    position: &PositionAddresses,
+) -> Instruction {
+    build(
+        ix_accounts::AcceptPositionTransfer {
+            new_depositor: *new_depositor,
+            collateral_account: position.collateral_account,
+            position_mint: position.position_mint,
+            position_token_account: position.position_token_account(depositor),
+            new_position_token_account: position.position_token_account(new_depositor),
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            new_depositor_blocklist_entry: find_blocklist_address(new_depositor).0,
+        },
+        ix_data::AcceptPositionTransfer {},
+    )
+}
+
+pub fn liquidate(
+    liquidator: &Pubkey,
+    position: &PositionAddresses,
+    price_update: &Pubkey,
+    token_program: &Pubkey,
+    amount_to_burn: u64,
+) -> Instruction {
+    build(
+        ix_accounts::Liquidate {
+            liquidator: *liquidator,
+            price_update: *price_update,
+            config_account: find_config_address().0,
+            collateral_account: position.collateral_account,
+            sol_account: position.sol_account,
+            mint_account: find_mint_address().0,
+            token_account: stablecoin_token_address(liquidator, token_program),
+            token_program: *token_program,
+            system_program: system_program::ID,
+            liquidator_blocklist_entry: find_blocklist_address(liquidator).0,
+        },
+        ix_data::Liquidate { amount_to_burn },
+    )
+}
+
+/// Liquidates every `(position, amount_to_burn)` pair at the same price.
+pub fn liquidate_batch(
+    liquidator: &Pubkey,
+    positions: &[(PositionAddresses, u64)],
+    price_update: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let mut instruction = build(
+        ix_accounts::LiquidateBatch {
+            liquidator: *liquidator,
+            price_update: *price_update,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            token_account: stablecoin_token_address(liquidator, token_program),
+            token_program: *token_program,
+            system_program: system_program::ID,
+            liquidator_blocklist_entry: find_blocklist_address(liquidator).0,
+        },
+        ix_data::LiquidateBatch {
+            amounts_to_burn: positions.iter().map(|(_, amount)| *amount).collect(),
+        },
+    );
+    for (position, _) in positions {
+        instruction
+            .accounts
+            .push(AccountMeta::new(position.collateral_account, false));
+        instruction
+            .accounts
+            .push(AccountMeta::new(position.sol_account, false));
+    }
+    instruction
+}
+
+// ============================================================================
+// Auctions
+// ============================================================================
+
+pub fn start_liquidation_auction(
+    starter: &Pubkey,
+    position: &PositionAddresses,
+    price_update: &Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::StartLiquidationAuction {
+            starter: *starter,
+            price_update: *price_update,
+            config_account: find_config_address().0,
+            collateral_account: position.collateral_account,
+            sol_account: position.sol_account,
+            auction: position.auction,
+            system_program: system_program::ID,
+            starter_blocklist_entry: find_blocklist_address(starter).0,
+        },
+        ix_data::StartLiquidationAuction {},
+    )
+}
+
+/// `starter` is the `Auction::starter` that gets the rent back when this bid settles it.
+pub fn bid(
+    bidder: &Pubkey,
+    position: &PositionAddresses,
+    starter: &Pubkey,
+    token_program: &Pubkey,
+    amount_to_burn: u64,
+) -> Instruction {
+    build(
+        ix_accounts::Bid {
+            bidder: *bidder,
+            config_account: find_config_address().0,
+            auction: position.auction,
+            collateral_account: position.collateral_account,
+            sol_account: position.sol_account,
+            starter: *starter,
+            mint_account: find_mint_address().0,
+            token_account: stablecoin_token_address(bidder, token_program),
+            token_program: *token_program,
+            system_program: system_program::ID,
+            bidder_blocklist_entry: find_blocklist_address(bidder).0,
+        },
+        ix_data::Bid { amount_to_burn },
+    )
+}
+
+// ============================================================================
+// Views
+// ============================================================================
+
+// run these with `simulateTransaction` and decode the return data with
+// `decode_return_data`
+
+fn view_position(collateral_account: &Pubkey, price_update: &Pubkey) -> ix_accounts::ViewPosition {
+    ix_accounts::ViewPosition {
+        config_account: find_config_address().0,
+        collateral_account: *collateral_account,
+        price_update: *price_update,
+    }
+}
+
+pub fn get_position_health(collateral_account: &Pubkey, price_update: &Pubkey) -> Instruction {
+    build(
+        view_position(collateral_account, price_update),
+        ix_data::GetPositionHealth {},
+    )
+}
+
+pub fn get_max_mintable(collateral_account: &Pubkey, price_update: &Pubkey) -> Instruction {
+    build(
+        view_position(collateral_account, price_update),
+        ix_data::GetMaxMintable {},
+    )
+}
+
+pub fn get_max_withdrawable(collateral_account: &Pubkey, price_update: &Pubkey) -> Instruction {
+    build(
+        view_position(collateral_account, price_update),
+        ix_data::GetMaxWithdrawable {},
+    )
+}
+
+pub fn get_liquidation_price(collateral_account: &Pubkey, price_update: &Pubkey) -> Instruction {
+    build(
+        view_position(collateral_account, price_update),
+        ix_data::GetLiquidationPrice {},
+    )
+}
+
+// ============================================================================
+// Operators
+// ============================================================================
+
+pub fn approve_operator(
+    depositor: &Pubkey,
+    position: &PositionAddresses,
+    operator: &Pubkey,
+    permissions: u8,
+    mint_cap: u64,
+) -> Instruction {
+    build(
+        ix_accounts::ApproveOperator {
+            depositor: *depositor,
+            collateral_account: position.collateral_account,
+            position_token_account: position.position_token_account(depositor),
+            operator: *operator,
+            delegation: find_delegation_address(&position.collateral_account, operator).0,
+            system_program: system_program::ID,
+        },
+        ix_data::ApproveOperator {
+            permissions,
+            mint_cap,
+        },
+    )
+}
+
+pub fn revoke_operator(
+    depositor: &Pubkey,
+    position: &PositionAddresses,
+    operator: &Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::RevokeOperator {
+            depositor: *depositor,
+            collateral_account: position.collateral_account,
+            position_token_account: position.position_token_account(depositor),
+            delegation: find_delegation_address(&position.collateral_account, operator).0,
+        },
+        ix_data::RevokeOperator {},
+    )
+}
+
+/// `owner` is the holder of the position NFT that approved `operator`.
+pub fn operator_deposit_collateral_and_mint_tokens(
+    operator: &Pubkey,
+    owner: &Pubkey,
+    position: &PositionAddresses,
+    price_update: &Pubkey,
+    token_program: &Pubkey,
+    amount_collateral: u64,
+    amount_to_mint: u64,
+) -> Instruction {
+    build(
+        ix_accounts::OperatorDepositCollateralAndMintTokens {
+            operator: *operator,
+            config_account: find_config_address().0,
+            collateral_account: position.collateral_account,
+            delegation: find_delegation_address(&position.collateral_account, operator).0,
+            owner_position_token_account: position.position_token_account(owner),
+            sol_account: position.sol_account,
+            mint_account: find_mint_address().0,
+            token_account: stablecoin_token_address(owner, token_program),
+            token_program: *token_program,
+            system_program: system_program::ID,
+            price_update: *price_update,
+            owner_blocklist_entry: find_blocklist_address(owner).0,
+            operator_blocklist_entry: find_blocklist_address(operator).0,
+            auction: position.auction,
+        },
+        ix_data::OperatorDepositCollateralAndMintTokens {
+            amount_collateral,
+            amount_to_mint,
+        },
+    )
+}
+
+/// The operator repays from its own token account, collateral goes to `owner`.
+pub fn operator_redeem_collateral_and_burn_tokens(
+    operator: &Pubkey,
+    owner: &Pubkey,
+    position: &PositionAddresses,
+    price_update: &Pubkey,
+    token_program: &Pubkey,
+    amount_collateral: u64,
+    amount_to_burn: u64,
+) -> Instruction {
+    build(
+        ix_accounts::OperatorRedeemCollateralAndBurnTokens {
+            operator: *operator,
+            price_update: *price_update,
+            config_account: find_config_address().0,
+            collateral_account: position.collateral_account,
+            delegation: find_delegation_address(&position.collateral_account, operator).0,
+            owner_position_token_account: position.position_token_account(owner),
+            depositor: *owner,
+            sol_account: position.sol_account,
+            mint_account: find_mint_address().0,
+            token_account: stablecoin_token_address(operator, token_program),
+            system_program: system_program::ID,
+            token_program: *token_program,
+            depositor_blocklist_entry: find_blocklist_address(owner).0,
+            operator_blocklist_entry: find_blocklist_address(operator).0,
+            auction: position.auction,
+        },
+        ix_data::OperatorRedeemCollateralAndBurnTokens {
+            amount_collateral,
+            amount_to_burn,
+        },
+    )
+}
+
+// ============================================================================
+// Savings
+// ============================================================================
+
+pub fn initialize_savings_vault(
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    savings_rate_bps: u64,
+    mint_budget: u64,
+) -> Instruction {
+    build(
+        ix_accounts::InitializeSavingsVault {
+            authority: *authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            savings_vault: find_savings_vault_address().0,
+            savings_token_account: find_savings_token_address().0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        ix_data::InitializeSavingsVault {
+            savings_rate_bps,
+            mint_budget,
+        },
+    )
+}
+
+pub fn update_savings_vault(
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    savings_rate_bps: u64,
+    mint_budget: u64,
+) -> Instruction {
+    build(
+        ix_accounts::UpdateSavingsVault {
+            authority: *authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            savings_vault: find_savings_vault_address().0,
+            savings_token_account: find_savings_token_address().0,
+            token_program: *token_program,
+        },
+        ix_data::UpdateSavingsVault {
+            savings_rate_bps,
+            mint_budget,
+        },
+    )
+}
+
+pub fn deposit_savings(depositor: &Pubkey, token_program: &Pubkey, amount: u64) -> Instruction {
+    let savings_vault = find_savings_vault_address().0;
+    let instruction = build(
+        ix_accounts::DepositSavings {
+            depositor: *depositor,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            savings_vault,
+            savings_token_account: find_savings_token_address().0,
+            savings_position: find_savings_position_address(depositor).0,
+            token_account: stablecoin_token_address(depositor, token_program),
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        ix_data::DepositSavings { amount },
+    );
+    with_transfer_hook_accounts(instruction, token_program, depositor, &savings_vault)
+}
+
+pub fn withdraw_savings(depositor: &Pubkey, token_program: &Pubkey, shares: u64) -> Instruction {
+    let savings_vault = find_savings_vault_address().0;
+    let instruction = build(
+        ix_accounts::WithdrawSavings {
+            depositor: *depositor,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            savings_vault,
+            savings_token_account: find_savings_token_address().0,
+            savings_position: find_savings_position_address(depositor).0,
+            token_account: stablecoin_token_address(depositor, token_program),
+            token_program: *token_program,
+        },
+        ix_data::WithdrawSavings { shares },
+    );
+    with_transfer_hook_accounts(instruction, token_program, &savings_vault, depositor)
+}
+
+/// `keeper` receives the accrual tip from the treasury when one is due.
+pub fn drip_savings(keeper: &Pubkey, token_program: &Pubkey) -> Instruction {
+    build(
+        ix_accounts::DripSavings {
+            keeper: *keeper,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            savings_vault: find_savings_vault_address().0,
+            savings_token_account: find_savings_token_address().0,
+            token_program: *token_program,
+            treasury: find_treasury_address().0,
+            system_program: system_program::ID,
+            keeper_blocklist_entry: find_blocklist_address(keeper).0,
+        },
+        ix_data::DripSavings {},
+    )
+}
+
+// ============================================================================
+// Recovery
+// ============================================================================
+
+/// `destination` is the token account to move the funds to, `None` burns them.
+pub fn schedule_recovery(
+    recovery_authority: &Pubkey,
+    token_account: &Pubkey,
+    amount: u64,
+    destination: Option<Pubkey>,
+) -> Instruction {
+    build(
+        ix_accounts::ScheduleRecovery {
+            recovery_authority: *recovery_authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            token_account: *token_account,
+            recovery_request: find_recovery_request_address(token_account).0,
+            system_program: system_program::ID,
+        },
+        ix_data::ScheduleRecovery {
+            amount,
+            destination,
+        },
+    )
+}
+
+pub fn cancel_recovery(
+    canceller: &Pubkey,
+    recovery_authority: &Pubkey,
+    token_account: &Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::CancelRecovery {
+            canceller: *canceller,
+            config_account: find_config_address().0,
+            recovery_authority: *recovery_authority,
+            recovery_request: find_recovery_request_address(token_account).0,
+        },
+        ix_data::CancelRecovery {},
+    )
+}
+
+pub fn force_burn(
+    recovery_authority: &Pubkey,
+    token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        ix_accounts::ForceBurn {
+            recovery_authority: *recovery_authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            token_account: *token_account,
+            recovery_request: find_recovery_request_address(token_account).0,
+            token_program: *token_program,
+        },
+        ix_data::ForceBurn {},
+    )
+}
+
+/// The owners are the wallets owning the two token accounts, used to resolve the hook accounts.
+pub fn force_transfer(
+    recovery_authority: &Pubkey,
+    source_token_account: &Pubkey,
+    source_owner: &Pubkey,
+    destination_token_account: &Pubkey,
+    destination_owner: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let instruction = build(
+        ix_accounts::ForceTransfer {
+            recovery_authority: *recovery_authority,
+            config_account: find_config_address().0,
+            mint_account: find_mint_address().0,
+            source_token_account: *source_token_account,
+            destination_token_account: *destination_token_account,
+            recovery_request: find_recovery_request_address(source_token_account).0,
+            token_program: *token_program,
+        },
+        ix_data::ForceTransfer {},
+    );
+    with_transfer_hook_accounts(instruction, token_program, source_owner, destination_owner)
+}
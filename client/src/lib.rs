@@ -0,0 +1,16 @@
+//! Off-chain helpers for the stablecoin program.
+//!
+//! Builders return plain `Instruction`s so they can be sent with any RPC client. Every
+//! address is derived with the seeds the program checks, and the health-factor math is
+//! the program's own `math` module, so a position that looks healthy here is healthy
+//! on-chain for the same price.
+pub mod accounts;
+pub mod health;
+pub mod instructions;
+pub mod pda;
+
+pub use accounts::*;
+pub use health::*;
+pub use instructions::*;
+pub use pda::*;
+pub use solana_stablecoin::{math, AmountMode, AuctionCurve, ID as PROGRAM_ID};
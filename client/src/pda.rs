@@ -0,0 +1,81 @@
+//! Addresses used by the stablecoin instructions.
+//!
+//! Program accounts come from `solana_stablecoin::pda`, this adds the token accounts and the
+//! transfer-hook accounts that the builders need.
+pub use solana_stablecoin::pda::*;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022;
+use stablecoin_transfer_hook::{
+    SEED_EXTRA_ACCOUNT_METAS, SEED_HOOK_CONFIG, SEED_TRANSFER_ALLOWANCE,
+};
+
+/// Accounts belonging to one position, derived from the depositor that opened it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PositionAddresses {
+    pub collateral_account: Pubkey,
+    pub sol_account: Pubkey,
+    pub position_mint: Pubkey,
+    pub auction: Pubkey,
+}
+
+impl PositionAddresses {
+    pub fn new(creator: &Pubkey, position_id: u64) -> Self {
+        let collateral_account = find_collateral_address(creator, position_id).0;
+        Self {
+            collateral_account,
+            sol_account: find_sol_address(creator, position_id).0,
+            position_mint: find_position_mint_address(&collateral_account).0,
+            auction: find_auction_address(&collateral_account).0,
+        }
+    }
+
+    /// Token account holding the position NFT when `holder` owns the position.
+    ///
+    /// Position NFTs are Token-2022 mints whichever program the stablecoin uses.
+    pub fn position_token_account(&self, holder: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(holder, &self.position_mint, &token_2022::ID)
+    }
+}
+
+/// The stablecoin associated token account of `owner`.
+pub fn stablecoin_token_address(owner: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, &find_mint_address().0, token_program)
+}
+
+pub fn find_hook_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_HOOK_CONFIG], &stablecoin_transfer_hook::ID)
+}
+
+pub fn find_extra_account_metas_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_EXTRA_ACCOUNT_METAS, find_mint_address().0.as_ref()],
+        &stablecoin_transfer_hook::ID,
+    )
+}
+
+pub fn find_transfer_allowance_address(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_TRANSFER_ALLOWANCE, owner.as_ref()],
+        &stablecoin_transfer_hook::ID,
+    )
+}
+
+/// Accounts Token-2022 needs to run the transfer hook when `source_owner` sends the
+/// stablecoin to `destination_owner`, appended to instructions that move it.
+pub fn transfer_hook_accounts(
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(stablecoin_transfer_hook::ID, false),
+        AccountMeta::new_readonly(find_extra_account_metas_address().0, false),
+        AccountMeta::new_readonly(solana_stablecoin::ID, false),
+        AccountMeta::new_readonly(find_hook_config_address().0, false),
+        AccountMeta::new_readonly(find_blocklist_address(source_owner).0, false),
+        AccountMeta::new_readonly(find_blocklist_address(destination_owner).0, false),
+        AccountMeta::new(find_transfer_allowance_address(source_owner).0, false),
+    ]
+}
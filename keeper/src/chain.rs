@@ -0,0 +1,113 @@
+use anchor_lang::{Discriminator, Space};
+use anyhow::{anyhow, Context, Result};
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    account::from_account,
+    clock::Clock,
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signature, Signer},
+    sysvar,
+    transaction::Transaction,
+};
+use solana_stablecoin::ANCHOR_DISCRIMINATOR;
+use solana_stablecoin_client::{
+    decode_account, decode_collateral, decode_config, find_config_address, sol_usd_price,
+    stablecoin_token_address, Collateral, Config,
+};
+
+use crate::config::KeeperConfig;
+
+/// RPC connection and the keeper's signer.
+pub struct Chain {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+}
+
+impl Chain {
+    pub fn new(config: &KeeperConfig) -> Result<Self> {
+        let keypair_path = config.keypair_path();
+        let payer = read_keypair_file(&keypair_path)
+            .map_err(|e| anyhow!("failed to read keypair {keypair_path}: {e}"))?;
+        Ok(Self {
+            rpc: RpcClient::new_with_commitment(
+                config.rpc_url.clone(),
+                CommitmentConfig::confirmed(),
+            ),
+            payer,
+        })
+    }
+
+    pub fn wallet(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    pub fn send(&self, instructions: &[Instruction]) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+        Ok(self.rpc.send_and_confirm_transaction(&transaction)?)
+    }
+
+    pub fn fetch_config(&self) -> Result<Config> {
+        let data = self
+            .rpc
+            .get_account_data(&find_config_address().0)
+            .context("config account not found")?;
+        Ok(decode_config(&data)?)
+    }
+
+    /// The price the program would read from `price_update` right now, so stale
+    /// updates are rejected here too.
+    pub fn fetch_price(&self, price_update: &Pubkey) -> Result<Price> {
+        let data = self.rpc.get_account_data(price_update)?;
+        let price_update: PriceUpdateV2 = decode_account(&data)?;
+        let clock: Clock = from_account(&self.rpc.get_account(&sysvar::clock::ID)?)
+            .context("failed to decode the clock sysvar")?;
+        Ok(sol_usd_price(&price_update, clock.unix_timestamp)?)
+    }
+
+    /// Stablecoin held by the keeper, 0 when it has no token account yet.
+    pub fn fetch_stablecoin_balance(&self, token_program: &Pubkey) -> Result<u64> {
+        let token_account = stablecoin_token_address(&self.wallet(), token_program);
+        match self.rpc.get_token_account_balance(&token_account) {
+            Ok(balance) => Ok(balance.amount.parse()?),
+            Err(_) => Ok(0),
+        }
+    }
+
+    pub fn fetch_positions(&self) -> Result<Vec<(Pubkey, Collateral)>> {
+        let accounts = self.rpc.get_program_accounts_with_config(
+            &solana_stablecoin::ID,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![
+                    RpcFilterType::DataSize((ANCHOR_DISCRIMINATOR + Collateral::INIT_SPACE) as u64),
+                    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                        0,
+                        Collateral::DISCRIMINATOR.to_vec(),
+                    )),
+                ]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..RpcAccountInfoConfig::default()
+                },
+                ..RpcProgramAccountsConfig::default()
+            },
+        )?;
+        accounts
+            .into_iter()
+            .map(|(address, account)| Ok((address, decode_collateral(&account.data)?)))
+            .collect()
+    }
+}
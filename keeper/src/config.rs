@@ -0,0 +1,41 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+use solana_sdk::pubkey::Pubkey;
+use std::{path::Path, time::Duration};
+
+/// Keeper settings read from a TOML file, see `keeper.example.toml`.
+#[serde_as]
+#[derive(Debug, Deserialize)]
+pub struct KeeperConfig {
+    pub rpc_url: String,
+    pub keypair_path: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price_update: Pubkey,
+    pub poll_interval_secs: u64,
+    pub min_profit: u64,
+    #[serde(default)]
+    pub max_liquidation_amount: u64,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl KeeperConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("invalid config {}", path.display()))
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval_secs)
+    }
+
+    /// `keypair_path` with a leading `~` expanded, like the Solana CLI does.
+    pub fn keypair_path(&self) -> String {
+        match (self.keypair_path.strip_prefix("~/"), std::env::var("HOME")) {
+            (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+            _ => self.keypair_path.clone(),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Reference liquidation keeper.
+//!
+//! Polls every position, and liquidates the unhealthy ones it can afford when the
+//! seized collateral is worth more than the stablecoin burned plus the fee.
+mod chain;
+mod config;
+mod planner;
+
+use anyhow::Result;
+use chain::Chain;
+use clap::Parser;
+use config::KeeperConfig;
+use log::{error, info, warn};
+use planner::plan_liquidation;
+use solana_stablecoin_client::{self as client, PositionAddresses};
+use std::path::PathBuf;
+
+// one signature, priority fees aren't used
+const TRANSACTION_FEE_LAMPORTS: u64 = 5_000;
+
+#[derive(Parser)]
+#[command(name = "stablecoin-keeper", version, about)]
+struct Cli {
+    /// Keeper configuration file
+    #[arg(short = 'c', long, default_value = "keeper.toml")]
+    config: PathBuf,
+    /// Run a single pass and exit
+    #[arg(long)]
+    once: bool,
+}
+
+fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let cli = Cli::parse();
+    let config = KeeperConfig::load(&cli.config)?;
+    let chain = Chain::new(&config)?;
+    info!(
+        "keeper {} watching {} (dry run: {})",
+        chain.wallet(),
+        config.rpc_url,
+        config.dry_run
+    );
+
+    loop {
+        if let Err(err) = run_once(&chain, &config) {
+            error!("pass failed: {err:#}");
+        }
+        if cli.once {
+            return Ok(());
+        }
+        std::thread::sleep(config.poll_interval());
+    }
+}
+
+fn run_once(chain: &Chain, config: &KeeperConfig) -> Result<()> {
+    let stablecoin_config = chain.fetch_config()?;
+    let price = chain.fetch_price(&config.price_update)?;
+    let token_program = stablecoin_config.token_program;
+    let mut available = chain.fetch_stablecoin_balance(&token_program)?;
+    let positions = chain.fetch_positions()?;
+    info!(
+        "{} positions, {available} stablecoin available",
+        positions.len()
+    );
+
+    for (address, collateral) in positions {
+        let Some(plan) = plan_liquidation(
+            &collateral,
+            &stablecoin_config,
+            &price,
+            available,
+            config.max_liquidation_amount,
+            TRANSACTION_FEE_LAMPORTS,
+            config.min_profit,
+        )?
+        else {
+            continue;
+        };
+        info!(
+            "liquidating {address}: burn {}, receive {} lamports, profit {}",
+            plan.amount_to_burn, plan.lamports_received, plan.profit
+        );
+        if config.dry_run {
+            continue;
+        }
+
+        let position = PositionAddresses::new(&collateral.creator, collateral.position_id);
+        let instruction = client::liquidate(
+            &chain.wallet(),
+            &position,
+            &config.price_update,
+            &token_program,
+            plan.amount_to_burn,
+        );
+        match chain.send(&[instruction]) {
+            Ok(signature) => {
+                info!("liquidated {address}: {signature}");
+                available -= plan.amount_to_burn;
+            }
+            // someone else may have liquidated it first, the next pass re-reads state
+            Err(err) => warn!("liquidation of {address} failed: {err:#}"),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Decides whether and how much of a position to liquidate.
+//!
+//! Uses the program's own `math` module, so a plan accepted here passes the program's
+//! checks for the same price.
+use anyhow::Result;
+use pyth_solana_receiver_sdk::price_update::Price;
+use solana_stablecoin_client::{
+    is_liquidatable,
+    math::{self, OraclePrice},
+    usd_value, Collateral, Config,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiquidationPlan {
+    pub amount_to_burn: u64,
+    pub lamports_received: u64,
+    pub profit: i128, // stablecoin base units, after the transaction fee
+}
+
+/// Plans the largest liquidation the keeper can afford, `None` when the position is
+/// healthy or the liquidation would not go through or not pay for itself.
+///
+/// # Arguments
+/// * `available` - Stablecoin the keeper holds
+/// * `max_amount` - Most stablecoin to burn at once, 0 means no limit
+/// * `fee_lamports` - What the liquidation transaction costs
+pub fn plan_liquidation(
+    collateral: &Collateral,
+    config: &Config,
+    price: &Price,
+    available: u64,
+    max_amount: u64,
+    fee_lamports: u64,
+    min_profit: u64,
+) -> Result<Option<LiquidationPlan>> {
+    if collateral.amount_minted == 0 || !is_liquidatable(collateral, config, price)? {
+        return Ok(None);
+    }
+
+    let mut amount_to_burn = collateral.amount_minted.min(available);
+    if max_amount > 0 {
+        amount_to_burn = amount_to_burn.min(max_amount);
+    }
+    if amount_to_burn == 0 {
+        return Ok(None);
+    }
+
+    // same math as `process_liquidate`, which also rejects payouts above the balance
+    let oracle_price = OraclePrice::new(price.price, price.exponent);
+    let Ok(outcome) = math::liquidate_position(
+        collateral.lamport_balance,
+        collateral.amount_minted,
+        amount_to_burn,
+        config.liquidation_bonus,
+        oracle_price,
+    ) else {
+        return Ok(None);
+    };
+    let lamports_received = outcome.lamports_paid;
+
+    // the program rejects liquidations that leave the position below the minimum
+    let health_factor = math::health_factor(
+        outcome.lamport_balance,
+        outcome.amount_minted,
+        config.liquidation_threshold,
+        oracle_price,
+    )?;
+    if math::is_liquidatable(health_factor, config.min_health_factor) {
+        return Ok(None);
+    }
+
+    let profit = usd_value(lamports_received, price)? as i128
+        - amount_to_burn as i128
+        - usd_value(fee_lamports, price)? as i128;
+    if profit < min_profit as i128 {
+        return Ok(None);
+    }
+    Ok(Some(LiquidationPlan {
+        amount_to_burn,
+        lamports_received,
+        profit,
+    }))
+}
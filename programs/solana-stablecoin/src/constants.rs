@@ -14,4 +14,36 @@ pub const SEED_SOL_ACCOUNT: &[u8] = b"sol";
 pub const SOL_USD_FEED_ID: &str =
     "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
 pub const MAXIMUM_AGE: u64 = 100;
-pub const PRICE_FEED_DECIMAL_ADJUSTMENT: u128 = 10; // the price feed returns 10^8 so we want to multiply by 10 to reach 10^9 so that the precision will be in lamports
+pub const SEED_SAVINGS_VAULT: &[u8] = b"savings_vault";
+pub const SEED_SAVINGS_TOKEN_ACCOUNT: &[u8] = b"savings_token";
+pub const SEED_SAVINGS_POSITION: &[u8] = b"savings_position";
+pub const SAVINGS_EXCHANGE_RATE_PRECISION: u128 = 1_000_000_000_000; // the share exchange rate is scaled by 10^12 and starts at 1
+pub const BASIS_POINTS: u64 = 10_000;
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+pub const SEED_POSITION_COUNTER: &[u8] = b"position_counter";
+pub const SEED_DELEGATION: &[u8] = b"delegation";
+// operator permissions are bit flags stored in `Delegation::permissions`
+pub const PERMISSION_ADD_COLLATERAL: u8 = 1 << 0;
+pub const PERMISSION_REPAY: u8 = 1 << 1;
+pub const PERMISSION_MINT: u8 = 1 << 2;
+pub const PERMISSION_REDEEM: u8 = 1 << 3; // collateral is always sent to the position owner
+pub const SEED_POSITION_MINT: &[u8] = b"position_mint";
+pub const POSITION_MINT_DECIMALS: u8 = 0;
+pub const SEED_BLOCKLIST: &[u8] = b"blocklist";
+pub const SEED_RECOVERY_REQUEST: &[u8] = b"recovery_request";
+// companion program enforcing transfer policies on the stablecoin mint,
+// F4W8xn5KbfL76c6qfkHGEKYn9R3n9C6TLBZ9L2aWv1wz
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    208, 233, 78, 21, 79, 118, 162, 0, 26, 48, 156, 42, 222, 132, 178, 59, 100, 220, 126, 211, 241,
+    59, 206, 92, 109, 184, 54, 245, 97, 20, 80, 237,
+]);
+pub const RECOVERY_TIMELOCK: i64 = 172_800; // 2 days between scheduling and executing a recovery
+pub const SEED_AUCTION: &[u8] = b"auction";
+pub const AUCTION_PREMIUM_BPS: u64 = 1_000; // auctions start 10% above the oracle price
+pub const AUCTION_FLOOR_BPS: u64 = 5_000; // and never go below half of it
+pub const AUCTION_DURATION: i64 = 3_600; // seconds the default linear curve takes to reach the floor
+pub const SEED_TREASURY: &[u8] = b"treasury";
+pub const KEEPER_TIP: u64 = 1_000_000; // lamports paid to whoever starts an auction or accrues savings
+pub const MAX_KEEPER_TIP: u64 = 10_000_000; // the authority can't raise the tip above 0.01 SOL
+pub const MAX_AUCTION_TIP_BPS: u64 = 100; // and an auction tip never takes more than 1% of the collateral
+pub const ACCRUAL_TIP_INTERVAL: i64 = 3_600; // savings left untouched this long earn the accrual tip
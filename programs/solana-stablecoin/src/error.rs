@@ -10,4 +10,52 @@ pub enum StablecointError {
     BelowMinimumHealthFactor,
     #[msg("Above minimum health factor")]
     AboveMinimumHealthFactor,
+    #[msg("Amount too small to receive any savings shares")]
+    ZeroSavingsShares,
+    #[msg("Not enough savings shares")]
+    InsufficientSavingsShares,
+    #[msg("Savings mint budget is below what has already been dripped")]
+    InvalidSavingsBudget,
+    #[msg("Position id is not the next one for this depositor")]
+    InvalidPositionId,
+    #[msg("Position still has outstanding debt")]
+    PositionHasDebt,
+    #[msg("Operator is not allowed to perform this action")]
+    OperatorNotPermitted,
+    #[msg("Operator mint cap exceeded")]
+    OperatorMintCapExceeded,
+    #[msg("Delegation was approved by a previous holder of the position")]
+    StaleDelegation,
+    #[msg("Signer does not hold the position NFT")]
+    NotPositionHolder,
+    #[msg("Signer is not the pending owner of the position")]
+    NotPendingDepositor,
+    #[msg("Address is blocklisted")]
+    Blocklisted,
+    #[msg("Token program does not match the one configured for the stablecoin")]
+    InvalidTokenProgram,
+    #[msg("Token extensions require the Token-2022 program")]
+    TokenExtensionsUnsupported,
+    #[msg("Recovery timelock has not elapsed")]
+    RecoveryTimelockActive,
+    #[msg("Recovery request is for a different action or destination")]
+    RecoveryActionMismatch,
+    #[msg("Signer may not cancel this recovery")]
+    NotRecoveryCanceller,
+    #[msg("Recovery timelock is shorter than the minimum")]
+    InvalidRecoveryTimelock,
+    #[msg("Recovery authority must be a separate key from the authority")]
+    InvalidRecoveryAuthority,
+    #[msg("Position does not hold enough collateral for this liquidation")]
+    InsufficientCollateral,
+    #[msg("Amount buffer cannot exceed 10000 basis points")]
+    InvalidAmountBuffer,
+    #[msg("Auction floor, premium or curve is invalid")]
+    InvalidAuctionParameters,
+    #[msg("Position has a liquidation auction in progress")]
+    AuctionInProgress,
+    #[msg("Liquidation batch needs a collateral and SOL account for every amount")]
+    InvalidLiquidationBatch,
+    #[msg("Keeper tip is above the maximum")]
+    InvalidKeeperTip,
 }
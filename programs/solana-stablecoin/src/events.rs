@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RecoveryScheduled {
+    pub token_account: Pubkey,
+    pub destination: Option<Pubkey>,
+    pub amount: u64,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct RecoveryCancelled {
+    pub token_account: Pubkey,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct ForceBurned {
+    pub token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ForceTransferred {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DonationsSwept {
+    pub collateral_account: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AuctionStarted {
+    pub collateral_account: Pubkey,
+    pub lamports: u64,
+    pub debt: u64,
+    pub start_price: i64,
+    pub floor_price: i64,
+    pub exponent: i32,
+}
+
+#[event]
+pub struct AuctionBid {
+    pub collateral_account: Pubkey,
+    pub bidder: Pubkey,
+    pub amount_burned: u64,
+    pub lamports_paid: u64,
+    pub price: i64,
+    pub exponent: i32,
+}
+
+#[event]
+pub struct AuctionSettled {
+    pub collateral_account: Pubkey,
+    pub surplus_lamports: u64,
+    pub bad_debt: u64,
+}
+
+#[event]
+pub struct BadDebtWrittenOff {
+    pub collateral_account: Pubkey,
+    pub amount: u64, // stablecoin left in circulation without collateral behind it
+}
+
+#[event]
+pub struct KeeperTipPaid {
+    pub keeper: Pubkey,
+    pub source: Pubkey, // the position whose collateral paid the tip, or the treasury
+    pub amount: u64,
+}
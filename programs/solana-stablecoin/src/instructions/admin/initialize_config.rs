@@ -1,9 +1,12 @@
 use crate::{
-    state::Config, ANCHOR_DISCRIMINATOR, LIQUIDATION_BONUS, LIQUIDATION_THRESHOLD, MINT_DECIMALS,
-    MIN_HEALTH_FACTOR, SEED_CONFIG_ACCOUNT, SEED_MINT_ACCOUNT,
+    fund_account_for_realloc, new_config, require_recovery_authority, state::Config,
+    token_metadata_space, ANCHOR_DISCRIMINATOR, MINT_DECIMALS, SEED_CONFIG_ACCOUNT,
+    SEED_MINT_ACCOUNT, TRANSFER_HOOK_PROGRAM_ID,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, Token2022};
+use anchor_spl::token_interface::{
+    token_metadata_initialize, Mint, Token2022, TokenMetadataInitialize,
+};
 // // what are the accounts we'll need to initialize the config?
 //  pub authority: Pubkey,
 //     pub mint_account: Pubkey,
@@ -26,6 +29,8 @@ pub struct InitializeConfig<'info> {
     )]
     pub config_account: Account<'info, Config>,
 
+    // the mint is its own metadata account and signs metadata updates itself,
+    // the config PDA is the permanent delegate used for recoveries
     #[account(
         init,
         payer = authority,
@@ -34,22 +39,70 @@ pub struct InitializeConfig<'info> {
         mint::decimals = MINT_DECIMALS,
         mint::authority = mint_account,
         mint::freeze_authority = mint_account,
-        mint::token_program = token_program
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = mint_account,
+        extensions::metadata_pointer::metadata_address = mint_account,
+        extensions::transfer_hook::authority = mint_account,
+        extensions::transfer_hook::program_id = transfer_hook_program,
+        extensions::permanent_delegate::delegate = config_account,
     )]
     pub mint_account: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: only its address is written into the TransferHook extension
+    #[account(address = TRANSFER_HOOK_PROGRAM_ID)]
+    pub transfer_hook_program: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn process_initialize_config(context: Context<InitializeConfig>) -> Result<()> {
-    *context.accounts.config_account = Config {
-        authority: context.accounts.authority.key(),
-        mint_account: context.accounts.mint_account.key(),
-        liquidation_threshold: LIQUIDATION_THRESHOLD,
-        liquidation_bonus: LIQUIDATION_BONUS,
-        min_health_factor: MIN_HEALTH_FACTOR,
-        bump: context.bumps.config_account,
-        bump_mint_account: context.bumps.mint_account,
-    };
+/// create the config and the Token-2022 stablecoin mint
+/// # Arguments
+/// * `name` - Token name shown by wallets
+/// * `symbol` - Token symbol
+/// * `uri` - Link to the off-chain token metadata json
+/// * `recovery_authority` - Key allowed to schedule and execute recoveries, separate from the authority
+pub fn process_initialize_config(
+    context: Context<InitializeConfig>,
+    name: String,
+    symbol: String,
+    uri: String,
+    recovery_authority: Pubkey,
+) -> Result<()> {
+    require_recovery_authority(context.accounts.authority.key(), recovery_authority)?;
+    *context.accounts.config_account = new_config(
+        context.accounts.authority.key(),
+        recovery_authority,
+        context.accounts.mint_account.key(),
+        context.accounts.token_program.key(),
+        context.bumps.config_account,
+        context.bumps.mint_account,
+    );
+
+    let mint_info = context.accounts.mint_account.to_account_info();
+    let new_len = mint_info.data_len() + token_metadata_space(&name, &symbol, &uri);
+    fund_account_for_realloc(
+        &context.accounts.authority,
+        &mint_info,
+        &context.accounts.system_program,
+        new_len,
+    )?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_MINT_ACCOUNT, &[context.bumps.mint_account]]];
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            context.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: context.accounts.token_program.to_account_info(),
+                metadata: mint_info.clone(),
+                update_authority: mint_info.clone(),
+                mint_authority: mint_info.clone(),
+                mint: mint_info,
+            },
+            signer_seeds,
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
     Ok(())
 }
@@ -0,0 +1,56 @@
+use crate::{
+    new_config, require_recovery_authority, state::Config, ANCHOR_DISCRIMINATOR, MINT_DECIMALS,
+    SEED_CONFIG_ACCOUNT, SEED_MINT_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct InitializeConfigLegacy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + Config::INIT_SPACE,
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump
+    )]
+    pub config_account: Account<'info, Config>,
+
+    // a classic SPL Token mint, so no metadata or transfer hook
+    #[account(
+        init,
+        payer = authority,
+        seeds = [SEED_MINT_ACCOUNT],
+        bump,
+        mint::decimals = MINT_DECIMALS,
+        mint::authority = mint_account,
+        mint::freeze_authority = mint_account,
+        mint::token_program = token_program,
+    )]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// create the config and a legacy SPL Token stablecoin mint
+/// # Arguments
+/// * `recovery_authority` - Key allowed to schedule and execute recoveries, separate from the authority
+pub fn process_initialize_config_legacy(
+    context: Context<InitializeConfigLegacy>,
+    recovery_authority: Pubkey,
+) -> Result<()> {
+    require_recovery_authority(context.accounts.authority.key(), recovery_authority)?;
+    *context.accounts.config_account = new_config(
+        context.accounts.authority.key(),
+        recovery_authority,
+        context.accounts.mint_account.key(),
+        context.accounts.token_program.key(),
+        context.bumps.config_account,
+        context.bumps.mint_account,
+    );
+    Ok(())
+}
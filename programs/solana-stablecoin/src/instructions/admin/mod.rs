@@ -1,4 +1,20 @@
 pub mod initialize_config;
 pub use initialize_config::*;
+pub mod initialize_config_legacy;
+pub use initialize_config_legacy::*;
+pub mod set_auction_parameters;
+pub use set_auction_parameters::*;
+pub mod set_compliance_authority;
+pub use set_compliance_authority::*;
+pub mod set_keeper_tip;
+pub use set_keeper_tip::*;
+pub mod set_recovery_authority;
+pub use set_recovery_authority::*;
+pub mod sweep_donations;
+pub use sweep_donations::*;
 pub mod update_config;
 pub use update_config::*;
+pub mod update_token_metadata;
+pub use update_token_metadata::*;
+pub mod utils;
+pub use utils::*;
@@ -0,0 +1,38 @@
+use crate::{
+    math::{self, AuctionCurve},
+    state::Config,
+    SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAuctionParameters<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = authority,
+    )]
+    pub config_account: Account<'info, Config>,
+}
+
+/// change how liquidation auctions are priced, running auctions keep their terms
+/// # Arguments
+/// * `premium_bps` - How far above the oracle price auctions start
+/// * `floor_bps` - Lowest auction price as a fraction of the oracle price
+/// * `curve` - How the price falls from the start to the floor
+pub fn process_set_auction_parameters(
+    context: Context<SetAuctionParameters>,
+    premium_bps: u64,
+    floor_bps: u64,
+    curve: AuctionCurve,
+) -> Result<()> {
+    math::validate_auction_parameters(premium_bps, floor_bps, curve)?;
+    let config_account = &mut context.accounts.config_account;
+    config_account.auction_premium_bps = premium_bps;
+    config_account.auction_floor_bps = floor_bps;
+    config_account.auction_curve = curve;
+    Ok(())
+}
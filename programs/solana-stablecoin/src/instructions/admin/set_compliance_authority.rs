@@ -0,0 +1,23 @@
+use crate::{state::Config, SEED_CONFIG_ACCOUNT};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetComplianceAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = authority,
+    )]
+    pub config_account: Account<'info, Config>,
+}
+
+pub fn process_set_compliance_authority(
+    context: Context<SetComplianceAuthority>,
+    compliance_authority: Pubkey,
+) -> Result<()> {
+    context.accounts.config_account.compliance_authority = compliance_authority;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::{error::StablecointError, state::Config, MAX_KEEPER_TIP, SEED_CONFIG_ACCOUNT};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetKeeperTip<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = authority,
+    )]
+    pub config_account: Account<'info, Config>,
+}
+
+/// change the tip for starting auctions and accruing savings, zero turns it off
+/// # Arguments
+/// * `keeper_tip` - Lamports per call, at most `MAX_KEEPER_TIP`
+pub fn process_set_keeper_tip(context: Context<SetKeeperTip>, keeper_tip: u64) -> Result<()> {
+    require!(
+        keeper_tip <= MAX_KEEPER_TIP,
+        StablecointError::InvalidKeeperTip
+    );
+    context.accounts.config_account.keeper_tip = keeper_tip;
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::{
+    error::StablecointError, require_recovery_authority, state::Config, RECOVERY_TIMELOCK,
+    SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRecoveryAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = authority,
+    )]
+    pub config_account: Account<'info, Config>,
+}
+
+/// hand the recovery role to another key
+/// # Arguments
+/// * `recovery_authority` - Key allowed to schedule and execute recoveries
+/// * `recovery_timelock` - Seconds between scheduling and executing a recovery, at least `RECOVERY_TIMELOCK`
+pub fn process_set_recovery_authority(
+    context: Context<SetRecoveryAuthority>,
+    recovery_authority: Pubkey,
+    recovery_timelock: i64,
+) -> Result<()> {
+    require!(
+        recovery_timelock >= RECOVERY_TIMELOCK,
+        StablecointError::InvalidRecoveryTimelock
+    );
+    let config_account = &mut context.accounts.config_account;
+    require_recovery_authority(config_account.authority, recovery_authority)?;
+    config_account.recovery_authority = recovery_authority;
+    config_account.recovery_timelock = recovery_timelock;
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::{
+    events::DonationsSwept,
+    state::{Collateral, Config},
+    withdraw_sol, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SweepDonations<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = authority,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+        has_one = sol_account,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(mut)]
+    pub sol_account: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// send lamports that reached a position's vault outside of deposits to the authority
+///
+/// The collateral and the vault's rent-exempt minimum stay behind.
+pub fn process_sweep_donations(context: Context<SweepDonations>) -> Result<()> {
+    let amount = context
+        .accounts
+        .sol_account
+        .lamports()
+        .saturating_sub(context.accounts.collateral_account.lamport_balance)
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    if amount == 0 {
+        return Ok(());
+    }
+
+    withdraw_sol(
+        &context.accounts.sol_account,
+        &context.accounts.authority,
+        &context.accounts.system_program,
+        &context.accounts.collateral_account.creator,
+        context.accounts.collateral_account.position_id,
+        context.accounts.collateral_account.bump_sol_account,
+        amount,
+    )?;
+
+    emit!(DonationsSwept {
+        collateral_account: context.accounts.collateral_account.key(),
+        amount,
+    });
+    Ok(())
+}
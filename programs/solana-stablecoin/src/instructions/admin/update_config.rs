@@ -3,10 +3,13 @@ use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
     #[account(
         mut,
         seeds = [SEED_CONFIG_ACCOUNT],
-        bump = config_account.bump
+        bump = config_account.bump,
+        has_one = authority,
     )]
     pub config_account: Account<'info, Config>,
 }
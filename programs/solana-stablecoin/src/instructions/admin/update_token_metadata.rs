@@ -0,0 +1,79 @@
+use crate::{
+    error::StablecointError, fund_account_for_realloc, state::Config, token_metadata_space,
+    SEED_CONFIG_ACCOUNT, SEED_MINT_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Mint, Token2022,
+    TokenInterface, TokenMetadataUpdateField,
+};
+
+#[derive(Accounts)]
+pub struct UpdateTokenMetadata<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = authority,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// replace the name, symbol and uri stored on the mint
+pub fn process_update_token_metadata(
+    context: Context<UpdateTokenMetadata>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    // legacy SPL Token mints have nowhere to store metadata
+    require_keys_eq!(
+        context.accounts.token_program.key(),
+        Token2022::id(),
+        StablecointError::TokenExtensionsUnsupported
+    );
+
+    // fund for the worst case where the new values are all stored next to the old ones
+    let mint_info = context.accounts.mint_account.to_account_info();
+    let new_len = mint_info.data_len() + token_metadata_space(&name, &symbol, &uri);
+    fund_account_for_realloc(
+        &context.accounts.authority,
+        &mint_info,
+        &context.accounts.system_program,
+        new_len,
+    )?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_MINT_ACCOUNT,
+        &[context.accounts.config_account.bump_mint_account],
+    ]];
+    for (field, value) in [
+        (Field::Name, name),
+        (Field::Symbol, symbol),
+        (Field::Uri, uri),
+    ] {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                context.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    token_program_id: context.accounts.token_program.to_account_info(),
+                    metadata: mint_info.clone(),
+                    update_authority: mint_info.clone(),
+                },
+                signer_seeds,
+            ),
+            field,
+            value,
+        )?;
+    }
+    Ok(())
+}
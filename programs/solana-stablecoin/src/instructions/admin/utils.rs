@@ -0,0 +1,86 @@
+use crate::{
+    error::StablecointError, math::AuctionCurve, state::Config, AUCTION_DURATION,
+    AUCTION_FLOOR_BPS, AUCTION_PREMIUM_BPS, KEEPER_TIP, LIQUIDATION_BONUS, LIQUIDATION_THRESHOLD,
+    MIN_HEALTH_FACTOR, RECOVERY_TIMELOCK,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+/// Config with the default risk parameters, shared by both mint flavours.
+///
+/// The authority also starts out as the compliance authority, callers check that
+/// `recovery_authority` is a separate key with `require_recovery_authority`.
+pub fn new_config(
+    authority: Pubkey,
+    recovery_authority: Pubkey,
+    mint_account: Pubkey,
+    token_program: Pubkey,
+    bump: u8,
+    bump_mint_account: u8,
+) -> Config {
+    Config {
+        authority,
+        mint_account,
+        compliance_authority: authority,
+        token_program,
+        recovery_authority,
+        recovery_timelock: RECOVERY_TIMELOCK,
+        liquidation_threshold: LIQUIDATION_THRESHOLD,
+        liquidation_bonus: LIQUIDATION_BONUS,
+        min_health_factor: MIN_HEALTH_FACTOR,
+        auction_premium_bps: AUCTION_PREMIUM_BPS,
+        auction_floor_bps: AUCTION_FLOOR_BPS,
+        auction_curve: AuctionCurve::Linear {
+            duration: AUCTION_DURATION,
+        },
+        keeper_tip: KEEPER_TIP,
+        bump,
+        bump_mint_account,
+    }
+}
+
+/// The recovery role can move anyone's tokens, so it may not sit on the admin key.
+pub fn require_recovery_authority(authority: Pubkey, recovery_authority: Pubkey) -> Result<()> {
+    require!(
+        recovery_authority != authority && recovery_authority != Pubkey::default(),
+        StablecointError::InvalidRecoveryAuthority
+    );
+    Ok(())
+}
+
+/// Bytes the TokenMetadata extension takes on the mint, including its TLV header.
+///
+/// update authority + mint + borsh strings for name, symbol and uri + an empty
+/// additional metadata vec.
+pub fn token_metadata_space(name: &str, symbol: &str, uri: &str) -> usize {
+    let tlv_header = 2 + 2;
+    tlv_header + 32 + 32 + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len()) + 4
+}
+
+/// Tops up `account` so it stays rent exempt once it grows to `new_len` bytes.
+///
+/// Token-2022 reallocates the mint itself when metadata is written but expects
+/// the lamports to already be there.
+pub fn fund_account_for_realloc<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if required_lamports == 0 {
+        return Ok(());
+    }
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+        ),
+        required_lamports,
+    )
+}
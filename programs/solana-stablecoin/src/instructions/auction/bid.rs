@@ -0,0 +1,145 @@
+use crate::{
+    burn_tokens,
+    error::StablecointError,
+    events::{AuctionBid, AuctionSettled, BadDebtWrittenOff},
+    math::{self, OraclePrice},
+    state::{Auction, Collateral, Config},
+    SEED_AUCTION, SEED_BLOCKLIST, SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct Bid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_AUCTION, collateral_account.key().as_ref()],
+        bump = auction.bump,
+        has_one = collateral_account,
+        has_one = starter,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    // surplus collateral goes back to the position
+    #[account(
+        mut,
+        has_one = sol_account,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(mut)]
+    pub sol_account: SystemAccount<'info>,
+
+    // gets the auction's rent back once it settles
+    #[account(mut)]
+    pub starter: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = bidder,
+        associated_token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, bidder.key().as_ref()],
+        bump,
+        constraint = bidder_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub bidder_blocklist_entry: UncheckedAccount<'info>,
+}
+
+/// buy seized collateral at the current auction price
+/// # Arguments
+/// * `amount_to_burn` - Most stablecoin to burn, capped at the debt left and at what the remaining collateral is worth
+pub fn process_bid(context: Context<Bid>, amount_to_burn: u64) -> Result<()> {
+    let auction = &context.accounts.auction;
+    let elapsed = Clock::get()?
+        .unix_timestamp
+        .saturating_sub(auction.started_at);
+    let price = OraclePrice::new(
+        math::auction_price(
+            auction.start_price,
+            auction.floor_price,
+            elapsed,
+            auction.curve,
+        )?,
+        auction.exponent,
+    );
+    let fill = math::auction_fill(auction.lamports, auction.debt, amount_to_burn, price)?;
+
+    burn_tokens(
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.token_account,
+        &context.accounts.bidder,
+        fill.amount_burned,
+    )?;
+    // the auction account is owned by the program, so lamports move without a CPI
+    context.accounts.auction.sub_lamports(fill.lamports_paid)?;
+    context.accounts.bidder.add_lamports(fill.lamports_paid)?;
+
+    let auction = &mut context.accounts.auction;
+    auction.lamports -= fill.lamports_paid;
+    auction.debt -= fill.amount_burned;
+    emit!(AuctionBid {
+        collateral_account: auction.collateral_account,
+        bidder: context.accounts.bidder.key(),
+        amount_burned: fill.amount_burned,
+        lamports_paid: fill.lamports_paid,
+        price: price.price,
+        exponent: price.exponent,
+    });
+
+    if auction.debt > 0 && auction.lamports > 0 {
+        return Ok(());
+    }
+    settle(context.accounts)
+}
+
+// hand the surplus back to the position, write off uncovered debt and close the auction
+fn settle(accounts: &mut Bid) -> Result<()> {
+    let surplus = accounts.auction.lamports;
+    let bad_debt = accounts.auction.debt;
+    accounts.auction.sub_lamports(surplus)?;
+    accounts.sol_account.add_lamports(surplus)?;
+
+    let collateral_account = &mut accounts.collateral_account;
+    collateral_account.lamport_balance = collateral_account
+        .lamport_balance
+        .checked_add(surplus)
+        .ok_or(StablecointError::MathError)?;
+    // collateral ran out before the debt was covered, the position has nothing left to
+    // back the rest so it is written off instead of charged back to it
+    if bad_debt > 0 {
+        emit!(BadDebtWrittenOff {
+            collateral_account: collateral_account.key(),
+            amount: bad_debt,
+        });
+    }
+
+    emit!(AuctionSettled {
+        collateral_account: collateral_account.key(),
+        surplus_lamports: surplus,
+        bad_debt,
+    });
+    accounts.auction.close(accounts.starter.to_account_info())
+}
@@ -0,0 +1,4 @@
+pub mod bid;
+pub use bid::*;
+pub mod start_liquidation_auction;
+pub use start_liquidation_auction::*;
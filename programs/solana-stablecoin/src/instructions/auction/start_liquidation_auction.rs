@@ -0,0 +1,152 @@
+use crate::{
+    calculate_health_factor, deposit_sol,
+    error::StablecointError,
+    events::{AuctionStarted, KeeperTipPaid},
+    get_sol_usd_price, math,
+    state::{Auction, Collateral, Config},
+    vault_rent_top_up, withdraw_sol, ANCHOR_DISCRIMINATOR, BASIS_POINTS, MAX_AUCTION_TIP_BPS,
+    SEED_AUCTION, SEED_BLOCKLIST, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+#[derive(Accounts)]
+pub struct StartLiquidationAuction<'info> {
+    #[account(mut)]
+    pub starter: Signer<'info>,
+    pub price_update: Account<'info, PriceUpdateV2>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+        has_one = sol_account,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(mut)]
+    pub sol_account: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = starter,
+        space = ANCHOR_DISCRIMINATOR + Auction::INIT_SPACE,
+        seeds = [SEED_AUCTION, collateral_account.key().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, starter.key().as_ref()],
+        bump,
+        constraint = starter_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub starter_blocklist_entry: UncheckedAccount<'info>,
+}
+
+// the whole position moves into the auction, its debt and collateral start again from zero
+pub fn process_start_liquidation_auction(context: Context<StartLiquidationAuction>) -> Result<()> {
+    let health_factor = calculate_health_factor(
+        &context.accounts.collateral_account,
+        &context.accounts.config_account,
+        &context.accounts.price_update,
+    )?;
+    require!(
+        health_factor < context.accounts.config_account.min_health_factor,
+        StablecointError::AboveMinimumHealthFactor
+    );
+
+    let config_account = &context.accounts.config_account;
+    let price = get_sol_usd_price(&context.accounts.price_update)?;
+    let start_price = math::scale_price(
+        price,
+        BASIS_POINTS
+            .checked_add(config_account.auction_premium_bps)
+            .ok_or(StablecointError::MathError)?,
+    )?;
+    let floor_price = math::scale_price(price, config_account.auction_floor_bps)?;
+
+    // surplus comes back to the vault, so it has to stay rent exempt without the collateral
+    let lamports = context.accounts.collateral_account.lamport_balance;
+    let rent_top_up = vault_rent_top_up(&context.accounts.sol_account, lamports)?;
+    deposit_sol(
+        &context.accounts.system_program,
+        &context.accounts.starter,
+        &context.accounts.sol_account,
+        rent_top_up,
+    )?;
+    // the starter is paid out of the seized collateral, the rest is auctioned
+    let tip = config_account.keeper_tip.min(math::mul_div(
+        lamports as u128,
+        MAX_AUCTION_TIP_BPS as u128,
+        BASIS_POINTS as u128,
+        math::Rounding::Down,
+    )?);
+    let lamports = lamports - tip;
+    withdraw_sol(
+        &context.accounts.sol_account,
+        &context.accounts.starter.to_account_info(),
+        &context.accounts.system_program,
+        &context.accounts.collateral_account.creator,
+        context.accounts.collateral_account.position_id,
+        context.accounts.collateral_account.bump_sol_account,
+        tip,
+    )?;
+    withdraw_sol(
+        &context.accounts.sol_account,
+        &context.accounts.auction.to_account_info(),
+        &context.accounts.system_program,
+        &context.accounts.collateral_account.creator,
+        context.accounts.collateral_account.position_id,
+        context.accounts.collateral_account.bump_sol_account,
+        lamports,
+    )?;
+
+    let collateral_account = &mut context.accounts.collateral_account;
+    let collateral_key = collateral_account.key();
+    let debt = collateral_account.amount_minted;
+    collateral_account.lamport_balance = 0;
+    collateral_account.amount_minted = 0;
+
+    *context.accounts.auction = Auction {
+        collateral_account: collateral_key,
+        starter: context.accounts.starter.key(),
+        lamports,
+        debt,
+        start_price: start_price.price,
+        floor_price: floor_price.price,
+        exponent: price.exponent,
+        curve: context.accounts.config_account.auction_curve,
+        started_at: Clock::get()?.unix_timestamp,
+        bump: context.bumps.auction,
+    };
+
+    emit!(AuctionStarted {
+        collateral_account: collateral_key,
+        lamports,
+        debt,
+        start_price: start_price.price,
+        floor_price: floor_price.price,
+        exponent: price.exponent,
+    });
+    if tip > 0 {
+        emit!(KeeperTipPaid {
+            keeper: context.accounts.starter.key(),
+            source: collateral_key,
+            amount: tip,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::{
+    state::{BlocklistEntry, Config},
+    ANCHOR_DISCRIMINATOR, SEED_BLOCKLIST, SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AddToBlocklist<'info> {
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = compliance_authority,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = compliance_authority,
+        space = ANCHOR_DISCRIMINATOR + BlocklistEntry::INIT_SPACE,
+        seeds = [SEED_BLOCKLIST, address.as_ref()],
+        bump
+    )]
+    pub blocklist_entry: Account<'info, BlocklistEntry>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_add_to_blocklist(context: Context<AddToBlocklist>, address: Pubkey) -> Result<()> {
+    *context.accounts.blocklist_entry = BlocklistEntry {
+        address,
+        bump: context.bumps.blocklist_entry,
+    };
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::{error::StablecointError, state::Config, SEED_CONFIG_ACCOUNT, SEED_MINT_ACCOUNT};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    freeze_account, FreezeAccount, Mint, TokenAccount, TokenInterface,
+};
+
+#[derive(Accounts)]
+pub struct FreezeTokenAccount<'info> {
+    pub compliance_authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = compliance_authority,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// the mint PDA is the freeze authority so the program signs for it
+pub fn process_freeze_token_account(context: Context<FreezeTokenAccount>) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_MINT_ACCOUNT,
+        &[context.accounts.config_account.bump_mint_account],
+    ]];
+    freeze_account(CpiContext::new_with_signer(
+        context.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: context.accounts.token_account.to_account_info(),
+            mint: context.accounts.mint_account.to_account_info(),
+            authority: context.accounts.mint_account.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
@@ -0,0 +1,8 @@
+pub mod add_to_blocklist;
+pub use add_to_blocklist::*;
+pub mod freeze_token_account;
+pub use freeze_token_account::*;
+pub mod remove_from_blocklist;
+pub use remove_from_blocklist::*;
+pub mod thaw_token_account;
+pub use thaw_token_account::*;
@@ -0,0 +1,30 @@
+use crate::{
+    state::{BlocklistEntry, Config},
+    SEED_BLOCKLIST, SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveFromBlocklist<'info> {
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = compliance_authority,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_BLOCKLIST, blocklist_entry.address.as_ref()],
+        bump = blocklist_entry.bump,
+        close = compliance_authority
+    )]
+    pub blocklist_entry: Account<'info, BlocklistEntry>,
+}
+
+pub fn process_remove_from_blocklist(_context: Context<RemoveFromBlocklist>) -> Result<()> {
+    Ok(())
+}
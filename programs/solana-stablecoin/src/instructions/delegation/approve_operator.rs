@@ -0,0 +1,66 @@
+use crate::{
+    error::StablecointError,
+    is_position_holder,
+    state::{Collateral, Delegation},
+    ANCHOR_DISCRIMINATOR, SEED_COLLATERAL_ACCOUNT, SEED_DELEGATION,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+pub struct ApproveOperator<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(
+        constraint = is_position_holder(&collateral_account, &position_token_account, &depositor.key())
+            @ StablecointError::NotPositionHolder
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: only the key is stored, the operator signs when it acts on the position
+    pub operator: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = ANCHOR_DISCRIMINATOR + Delegation::INIT_SPACE,
+        seeds = [SEED_DELEGATION, collateral_account.key().as_ref(), operator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+    pub system_program: Program<'info, System>,
+}
+
+/// approve or update an operator on a position
+/// # Arguments
+/// * `permissions` - Bit flags built from the `PERMISSION_*` constants
+/// * `mint_cap` - Most debt the operator may have outstanding on the position
+pub fn process_approve_operator(
+    context: Context<ApproveOperator>,
+    permissions: u8,
+    mint_cap: u64,
+) -> Result<()> {
+    let delegation = &mut context.accounts.delegation;
+    // a delegation left over from a previous owner starts again from nothing
+    if delegation.depositor != context.accounts.depositor.key() {
+        delegation.amount_minted = 0;
+    }
+    delegation.collateral_account = context.accounts.collateral_account.key();
+    delegation.operator = context.accounts.operator.key();
+    delegation.depositor = context.accounts.depositor.key();
+    delegation.permissions = permissions;
+    delegation.mint_cap = mint_cap;
+    delegation.bump = context.bumps.delegation;
+    Ok(())
+}
@@ -0,0 +1,10 @@
+pub mod approve_operator;
+pub use approve_operator::*;
+pub mod operator_deposit_collateral_and_mint_tokens;
+pub use operator_deposit_collateral_and_mint_tokens::*;
+pub mod operator_redeem_collateral_and_burn_tokens;
+pub use operator_redeem_collateral_and_burn_tokens::*;
+pub mod revoke_operator;
+pub use revoke_operator::*;
+pub mod utils;
+pub use utils::*;
@@ -0,0 +1,144 @@
+use crate::{
+    check_health_factor, deposit_sol,
+    error::StablecointError,
+    is_position_holder, mint_tokens, record_operator_mint, require_permission,
+    state::{Collateral, Config, Delegation},
+    vault_rent_top_up, PERMISSION_ADD_COLLATERAL, SEED_AUCTION, SEED_BLOCKLIST,
+    SEED_CONFIG_ACCOUNT, SEED_DELEGATION,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+#[derive(Accounts)]
+pub struct OperatorDepositCollateralAndMintTokens<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = mint_account,
+    )]
+    pub config_account: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        has_one = sol_account,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DELEGATION, collateral_account.key().as_ref(), operator.key().as_ref()],
+        bump = delegation.bump,
+        has_one = collateral_account,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    // the owner that approved the operator must still hold the position NFT
+    #[account(
+        constraint = is_position_holder(&collateral_account, &owner_position_token_account, &delegation.depositor)
+            @ StablecointError::StaleDelegation
+    )]
+    pub owner_position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub sol_account: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    // minted stablecoin always goes to the position owner
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::token_program = token_program,
+        constraint = token_account.owner == delegation.depositor,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub price_update: Account<'info, PriceUpdateV2>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, delegation.depositor.as_ref()],
+        bump,
+        constraint = owner_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub owner_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, operator.key().as_ref()],
+        bump,
+        constraint = operator_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub operator_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only holds data while an auction sells the position's seized collateral
+    #[account(
+        seeds = [SEED_AUCTION, collateral_account.key().as_ref()],
+        bump,
+        constraint = auction.data_is_empty() @ StablecointError::AuctionInProgress
+    )]
+    pub auction: UncheckedAccount<'info>,
+}
+
+/// add collateral and/or mint on behalf of the position owner
+/// # Arguments
+/// * `amount_collateral` - Lamports paid by the operator into the position
+/// * `amount_to_mint` - Stablecoin minted to the owner, counted against the mint cap
+pub fn process_operator_deposit_collateral_and_mint_tokens(
+    context: Context<OperatorDepositCollateralAndMintTokens>,
+    amount_collateral: u64,
+    amount_to_mint: u64,
+) -> Result<()> {
+    if amount_collateral > 0 {
+        require_permission(&context.accounts.delegation, PERMISSION_ADD_COLLATERAL)?;
+    }
+    if amount_to_mint > 0 {
+        record_operator_mint(&mut context.accounts.delegation, amount_to_mint)?;
+    }
+
+    let rent_top_up = vault_rent_top_up(
+        &context.accounts.sol_account,
+        context.accounts.collateral_account.lamport_balance,
+    )?;
+    let collateral_account = &mut context.accounts.collateral_account;
+    collateral_account.lamport_balance = collateral_account
+        .lamport_balance
+        .checked_add(amount_collateral)
+        .ok_or(StablecointError::MathError)?;
+    collateral_account.amount_minted = collateral_account
+        .amount_minted
+        .checked_add(amount_to_mint)
+        .ok_or(StablecointError::MathError)?;
+
+    check_health_factor(
+        &context.accounts.collateral_account,
+        &context.accounts.config_account,
+        &context.accounts.price_update,
+    )?;
+
+    deposit_sol(
+        &context.accounts.system_program,
+        &context.accounts.operator,
+        &context.accounts.sol_account,
+        amount_collateral
+            .checked_add(rent_top_up)
+            .ok_or(StablecointError::MathError)?,
+    )?;
+
+    mint_tokens(
+        context.accounts.config_account.bump_mint_account,
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.token_account,
+        amount_to_mint,
+    )?;
+    Ok(())
+}
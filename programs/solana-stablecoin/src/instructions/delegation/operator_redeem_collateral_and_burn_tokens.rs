@@ -0,0 +1,148 @@
+use crate::{
+    burn_tokens, check_health_factor,
+    error::StablecointError,
+    is_position_holder, record_operator_repay, require_permission,
+    state::{Collateral, Config, Delegation},
+    vault_withdrawal, withdraw_sol, PERMISSION_REDEEM, SEED_AUCTION, SEED_BLOCKLIST,
+    SEED_CONFIG_ACCOUNT, SEED_DELEGATION,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+#[derive(Accounts)]
+pub struct OperatorRedeemCollateralAndBurnTokens<'info> {
+    pub operator: Signer<'info>,
+    pub price_update: Account<'info, PriceUpdateV2>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = mint_account
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = sol_account,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DELEGATION, collateral_account.key().as_ref(), operator.key().as_ref()],
+        bump = delegation.bump,
+        has_one = collateral_account,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    // the owner that approved the operator must still hold the position NFT
+    #[account(
+        constraint = is_position_holder(&collateral_account, &owner_position_token_account, &delegation.depositor)
+            @ StablecointError::StaleDelegation
+    )]
+    pub owner_position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // redeemed collateral can only go to the position owner
+    #[account(mut, address = delegation.depositor)]
+    pub depositor: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub sol_account: SystemAccount<'info>,
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    // the operator repays from its own token account
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::authority = operator,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, depositor.key().as_ref()],
+        bump,
+        constraint = depositor_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub depositor_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, operator.key().as_ref()],
+        bump,
+        constraint = operator_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub operator_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only holds data while an auction sells the position's seized collateral
+    #[account(
+        seeds = [SEED_AUCTION, collateral_account.key().as_ref()],
+        bump,
+        constraint = auction.data_is_empty() @ StablecointError::AuctionInProgress
+    )]
+    pub auction: UncheckedAccount<'info>,
+}
+
+/// repay and/or redeem collateral to the owner on their behalf
+/// # Arguments
+/// * `amount_collateral` - Lamports sent from the position to the owner
+/// * `amount_to_burn` - Stablecoin burned from the operator's token account
+pub fn process_operator_redeem_collateral_and_burn_tokens(
+    context: Context<OperatorRedeemCollateralAndBurnTokens>,
+    amount_collateral: u64,
+    amount_to_burn: u64,
+) -> Result<()> {
+    if amount_collateral > 0 {
+        require_permission(&context.accounts.delegation, PERMISSION_REDEEM)?;
+    }
+    if amount_to_burn > 0 {
+        record_operator_repay(&mut context.accounts.delegation, amount_to_burn)?;
+    }
+
+    let collateral_account = &mut context.accounts.collateral_account;
+    collateral_account.lamport_balance = collateral_account
+        .lamport_balance
+        .checked_sub(amount_collateral)
+        .ok_or(StablecointError::MathError)?;
+    collateral_account.amount_minted = collateral_account
+        .amount_minted
+        .checked_sub(amount_to_burn)
+        .ok_or(StablecointError::MathError)?;
+    let lamports_out = vault_withdrawal(
+        &context.accounts.sol_account,
+        amount_collateral,
+        collateral_account.lamport_balance,
+    )?;
+
+    check_health_factor(
+        &context.accounts.collateral_account,
+        &context.accounts.config_account,
+        &context.accounts.price_update,
+    )?;
+
+    burn_tokens(
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.token_account,
+        &context.accounts.operator,
+        amount_to_burn,
+    )?;
+
+    withdraw_sol(
+        &context.accounts.sol_account,
+        &context.accounts.depositor.to_account_info(),
+        &context.accounts.system_program,
+        &context.accounts.collateral_account.creator,
+        context.accounts.collateral_account.position_id,
+        context.accounts.collateral_account.bump_sol_account,
+        lamports_out,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::{
+    error::StablecointError,
+    is_position_holder,
+    state::{Collateral, Delegation},
+    SEED_COLLATERAL_ACCOUNT, SEED_DELEGATION,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+pub struct RevokeOperator<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(
+        constraint = is_position_holder(&collateral_account, &position_token_account, &depositor.key())
+            @ StablecointError::NotPositionHolder
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DELEGATION, collateral_account.key().as_ref(), delegation.operator.as_ref()],
+        bump = delegation.bump,
+        has_one = collateral_account,
+        close = depositor
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+pub fn process_revoke_operator(_context: Context<RevokeOperator>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::{error::StablecointError, state::Delegation, PERMISSION_MINT, PERMISSION_REPAY};
+use anchor_lang::prelude::*;
+
+pub fn require_permission(delegation: &Delegation, permission: u8) -> Result<()> {
+    require!(
+        delegation.has_permission(permission),
+        StablecointError::OperatorNotPermitted
+    );
+    Ok(())
+}
+
+/// Records debt minted by the operator, failing if it would go over the mint cap.
+pub fn record_operator_mint(delegation: &mut Delegation, amount: u64) -> Result<()> {
+    require_permission(delegation, PERMISSION_MINT)?;
+    let amount_minted = delegation
+        .amount_minted
+        .checked_add(amount)
+        .ok_or(StablecointError::MathError)?;
+    require!(
+        amount_minted <= delegation.mint_cap,
+        StablecointError::OperatorMintCapExceeded
+    );
+    delegation.amount_minted = amount_minted;
+    Ok(())
+}
+
+/// Repayments by the operator free up room under its mint cap.
+pub fn record_operator_repay(delegation: &mut Delegation, amount: u64) -> Result<()> {
+    require_permission(delegation, PERMISSION_REPAY)?;
+    delegation.amount_minted = delegation.amount_minted.saturating_sub(amount);
+    Ok(())
+}
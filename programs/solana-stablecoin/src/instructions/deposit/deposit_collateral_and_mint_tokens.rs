@@ -1,14 +1,16 @@
 use crate::{
     check_health_factor, deposit_sol,
     error::StablecointError,
+    get_sol_usd_price, is_position_holder,
+    math::{self, AmountMode},
     mint_tokens,
     state::{Collateral, Config},
-    ANCHOR_DISCRIMINATOR, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT, SEED_SOL_ACCOUNT,
+    vault_rent_top_up, SEED_AUCTION, SEED_BLOCKLIST, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{Mint, Token2022, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
@@ -26,19 +28,24 @@ pub struct DepositCollateralAndMintTokens<'info> {
     pub config_account: Box<Account<'info, Config>>,
 
     #[account(
-        init_if_needed,
-        payer = depositor,
-        space = ANCHOR_DISCRIMINATOR + Collateral::INIT_SPACE,
-        seeds = [SEED_COLLATERAL_ACCOUNT, depositor.key().as_ref()],
-        bump
+        mut,
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+        has_one = sol_account,
     )]
     pub collateral_account: Account<'info, Collateral>,
 
     #[account(
-        mut,
-        seeds = [SEED_SOL_ACCOUNT, depositor.key().as_ref()],
-        bump
+        constraint = is_position_holder(&collateral_account, &position_token_account, &depositor.key())
+            @ StablecointError::NotPositionHolder
     )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
     pub sol_account: SystemAccount<'info>,
 
     #[account(
@@ -50,37 +57,63 @@ pub struct DepositCollateralAndMintTokens<'info> {
     )]
     pub token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token2022>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub price_update: Account<'info, PriceUpdateV2>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, depositor.key().as_ref()],
+        bump,
+        constraint = depositor_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub depositor_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only holds data while an auction sells the position's seized collateral
+    #[account(
+        seeds = [SEED_AUCTION, collateral_account.key().as_ref()],
+        bump,
+        constraint = auction.data_is_empty() @ StablecointError::AuctionInProgress
+    )]
+    pub auction: UncheckedAccount<'info>,
 }
 
+/// deposit SOL into the position and mint stablecoin against it
+/// # Arguments
+/// * `amount_collateral` - Lamports to deposit, the first deposit also pays the vault's rent-exempt minimum
+/// * `amount_to_mint` - Stablecoin to mint, `MaxSafe` and `All` are sized at the current price including the deposit
 pub fn process_deposit_collateral_and_mint_tokens(
     context: Context<DepositCollateralAndMintTokens>,
     amount_collateral: u64,
-    amount_to_mint: u64,
+    amount_to_mint: AmountMode,
 ) -> Result<()> {
+    let rent_top_up = vault_rent_top_up(
+        &context.accounts.sol_account,
+        context.accounts.collateral_account.lamport_balance,
+    )?;
     let collateral_account = &mut context.accounts.collateral_account;
-    collateral_account.lamport_balance = context
-        .accounts
-        .sol_account
-        .lamports()
+    collateral_account.depositor = context.accounts.depositor.key();
+    collateral_account.token_account = context.accounts.token_account.key();
+    // only what is deposited here counts, lamports sent straight to the vault don't
+    collateral_account.lamport_balance = collateral_account
+        .lamport_balance
         .checked_add(amount_collateral)
         .ok_or(StablecointError::MathError)?;
+    let amount_to_mint = math::mint_amount(
+        amount_to_mint,
+        collateral_account.lamport_balance,
+        collateral_account.amount_minted,
+        context.accounts.config_account.liquidation_threshold,
+        context.accounts.config_account.min_health_factor,
+        get_sol_usd_price(&context.accounts.price_update)?,
+    )?;
     collateral_account.amount_minted = collateral_account
         .amount_minted
         .checked_add(amount_to_mint)
         .ok_or(StablecointError::MathError)?;
 
-    if !collateral_account.is_initialized {
-        collateral_account.is_initialized = true;
-        collateral_account.depositor = context.accounts.depositor.key();
-        collateral_account.sol_account = context.accounts.sol_account.key();
-        collateral_account.token_account = context.accounts.token_account.key();
-        collateral_account.bump = context.bumps.collateral_account;
-    }
-
     // why are we checking the health factor?
     check_health_factor(
         &context.accounts.collateral_account,
@@ -92,7 +125,9 @@ pub fn process_deposit_collateral_and_mint_tokens(
         &context.accounts.system_program,
         &context.accounts.depositor,
         &context.accounts.sol_account,
-        amount_collateral,
+        amount_collateral
+            .checked_add(rent_top_up)
+            .ok_or(StablecointError::MathError)?,
     )?;
 
     mint_tokens(
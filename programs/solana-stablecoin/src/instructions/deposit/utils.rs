@@ -1,20 +1,17 @@
-use crate::helpers::{mint_spl_tokens_2022, transfer_sol_from_user};
+use crate::helpers::{mint_tokens_with_pda_authority, transfer_sol_from_user};
 use crate::SEED_MINT_ACCOUNT;
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token_2022::Token2022,
-    token_interface::{Mint, TokenAccount},
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 pub fn mint_tokens<'info>(
     bump: u8,
-    token_program: &Program<'info, Token2022>,
+    token_program: &Interface<'info, TokenInterface>,
     mint_account: &InterfaceAccount<'info, Mint>,
     token_account: &InterfaceAccount<'info, TokenAccount>,
     amount: u64,
 ) -> Result<()> {
     let signer_seeds: &[&[&[u8]]] = &[&[SEED_MINT_ACCOUNT, &[bump]]];
-    mint_spl_tokens_2022(
+    mint_tokens_with_pda_authority(
         mint_account,
         token_account,
         mint_account,
@@ -34,3 +31,12 @@ pub fn deposit_sol<'info>(
     transfer_sol_from_user(from, to, amount, system_program)?;
     Ok(())
 }
+
+/// Lamports to pay into the vault on top of the collateral so it keeps the rent-exempt
+/// minimum besides the `lamport_balance` it already holds.
+///
+/// The reserve isn't collateral, `close_position` hands it back with the vault.
+pub fn vault_rent_top_up(sol_account: &SystemAccount, lamport_balance: u64) -> Result<u64> {
+    let reserve = sol_account.lamports().saturating_sub(lamport_balance);
+    Ok(Rent::get()?.minimum_balance(0).saturating_sub(reserve))
+}
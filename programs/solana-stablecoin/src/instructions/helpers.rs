@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
 use anchor_spl::token_interface::{
-    mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+    mint_to, set_authority, transfer_checked, Mint, MintTo, SetAuthority, TokenAccount,
+    TokenInterface, TransferChecked,
 };
 
 // ============================================================================
@@ -150,13 +151,13 @@ pub fn transfer_sol_from_pda<'info>(
 /// - `amount` is raw units
 /// - decimals are NOT verified
 ///
-/// This is required for Token-2022 compatibility.
-pub fn mint_spl_tokens_2022<'info>(
+/// Works with both SPL Token and Token-2022 mints.
+pub fn mint_tokens_with_pda_authority<'info>(
     mint: &InterfaceAccount<'info, Mint>,
     to: &InterfaceAccount<'info, TokenAccount>,
     mint_authority: &InterfaceAccount<'info, Mint>,
     amount: u64,
-    token_program: &Program<'info, Token2022>,
+    token_program: &Interface<'info, TokenInterface>,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
     mint_to(
@@ -173,6 +174,29 @@ pub fn mint_spl_tokens_2022<'info>(
     )
 }
 
+/// Permanently removes the mint authority of a mint whose authority is a **PDA**.
+///
+/// Used to fix the supply of a mint once it has been minted, e.g. for NFTs.
+pub fn remove_mint_authority<'info>(
+    mint: &InterfaceAccount<'info, Mint>,
+    mint_authority: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    set_authority(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            SetAuthority {
+                current_authority: mint_authority.to_account_info(),
+                account_or_mint: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )
+}
+
 /// Mints tokens using a **user signer** as mint authority.
 ///
 /// ⚠️ Unchecked:
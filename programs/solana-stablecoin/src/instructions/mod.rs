@@ -1,10 +1,24 @@
 pub mod admin;
 pub use admin::*;
+pub mod auction;
+pub use auction::*;
+pub mod compliance;
+pub use compliance::*;
+pub mod delegation;
+pub use delegation::*;
 pub mod deposit;
 pub use deposit::*;
 pub mod helpers;
 pub use helpers::*;
+pub mod position;
+pub use position::*;
+pub mod recovery;
+pub use recovery::*;
+pub mod savings;
+pub use savings::*;
 pub mod utils;
 pub use utils::*;
+pub mod view;
+pub use view::*;
 pub mod withdraw;
 pub use withdraw::*;
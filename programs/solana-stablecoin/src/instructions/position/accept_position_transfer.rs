@@ -0,0 +1,93 @@
+use crate::{
+    error::StablecointError, is_position_holder, state::Collateral, SEED_BLOCKLIST,
+    SEED_COLLATERAL_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, Token2022, TokenAccount, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct AcceptPositionTransfer<'info> {
+    #[account(mut)]
+    pub new_depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+        has_one = position_mint,
+        constraint = collateral_account.pending_depositor == new_depositor.key() @ StablecointError::NotPendingDepositor,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // the NFT is still with whoever proposed the transfer
+    #[account(
+        mut,
+        constraint = is_position_holder(&collateral_account, &position_token_account, &collateral_account.depositor)
+            @ StablecointError::NotPositionHolder
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = new_depositor,
+        associated_token::mint = position_mint,
+        associated_token::authority = new_depositor,
+        associated_token::token_program = token_program
+    )]
+    pub new_position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, new_depositor.key().as_ref()],
+        bump,
+        constraint = new_depositor_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub new_depositor_blocklist_entry: UncheckedAccount<'info>,
+}
+
+// collateral and debt stay in place, the NFT moves to the new owner
+pub fn process_accept_position_transfer(context: Context<AcceptPositionTransfer>) -> Result<()> {
+    let collateral_account = &context.accounts.collateral_account;
+    let position_id = collateral_account.position_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_COLLATERAL_ACCOUNT,
+        collateral_account.creator.as_ref(),
+        &position_id,
+        &[collateral_account.bump],
+    ]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            context.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: context.accounts.position_token_account.to_account_info(),
+                mint: context.accounts.position_mint.to_account_info(),
+                to: context
+                    .accounts
+                    .new_position_token_account
+                    .to_account_info(),
+                authority: collateral_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+        context.accounts.position_mint.decimals,
+    )?;
+
+    let collateral_account = &mut context.accounts.collateral_account;
+    collateral_account.depositor = context.accounts.new_depositor.key();
+    collateral_account.pending_depositor = Pubkey::default();
+    Ok(())
+}
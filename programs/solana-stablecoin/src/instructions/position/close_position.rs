@@ -0,0 +1,113 @@
+use crate::{
+    burn_tokens,
+    error::StablecointError,
+    is_position_holder,
+    state::{Collateral, Config},
+    withdraw_sol, SEED_AUCTION, SEED_BLOCKLIST, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+        has_one = sol_account,
+        has_one = position_mint,
+        close = depositor
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(
+        mut,
+        constraint = is_position_holder(&collateral_account, &position_token_account, &depositor.key())
+            @ StablecointError::NotPositionHolder
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub sol_account: SystemAccount<'info>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    // position NFTs are always Token-2022, whichever program the stablecoin uses
+    #[account(address = token_2022::ID)]
+    pub position_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, depositor.key().as_ref()],
+        bump,
+        constraint = depositor_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub depositor_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only holds data while an auction sells the position's seized collateral
+    #[account(
+        seeds = [SEED_AUCTION, collateral_account.key().as_ref()],
+        bump,
+        constraint = auction.data_is_empty() @ StablecointError::AuctionInProgress
+    )]
+    pub auction: UncheckedAccount<'info>,
+}
+
+// the collateral account rent goes back through `close`, the SOL vault and the NFT
+// token account are emptied and closed manually
+pub fn process_close_position(context: Context<ClosePosition>) -> Result<()> {
+    require!(
+        context.accounts.collateral_account.amount_minted == 0,
+        StablecointError::PositionHasDebt
+    );
+
+    let remaining_lamports = context.accounts.sol_account.lamports();
+    if remaining_lamports > 0 {
+        withdraw_sol(
+            &context.accounts.sol_account,
+            &context.accounts.depositor.to_account_info(),
+            &context.accounts.system_program,
+            &context.accounts.collateral_account.creator,
+            context.accounts.collateral_account.position_id,
+            context.accounts.collateral_account.bump_sol_account,
+            remaining_lamports,
+        )?;
+    }
+
+    // the NFT of a closed position is worthless, burn it so it can't be traded on
+    burn_tokens(
+        &context.accounts.position_token_program,
+        &context.accounts.position_mint,
+        &context.accounts.position_token_account,
+        &context.accounts.depositor,
+        1,
+    )?;
+    close_account(CpiContext::new(
+        context.accounts.position_token_program.to_account_info(),
+        CloseAccount {
+            account: context.accounts.position_token_account.to_account_info(),
+            destination: context.accounts.depositor.to_account_info(),
+            authority: context.accounts.depositor.to_account_info(),
+        },
+    ))?;
+    Ok(())
+}
@@ -0,0 +1,10 @@
+pub mod open_position;
+pub use open_position::*;
+pub mod close_position;
+pub use close_position::*;
+pub mod propose_position_transfer;
+pub use propose_position_transfer::*;
+pub mod accept_position_transfer;
+pub use accept_position_transfer::*;
+pub mod utils;
+pub use utils::*;
@@ -0,0 +1,153 @@
+use crate::{
+    error::StablecointError,
+    helpers::{mint_tokens_with_pda_authority, remove_mint_authority},
+    state::{Collateral, Config, PositionCounter},
+    ANCHOR_DISCRIMINATOR, POSITION_MINT_DECIMALS, SEED_BLOCKLIST, SEED_COLLATERAL_ACCOUNT,
+    SEED_CONFIG_ACCOUNT, SEED_POSITION_COUNTER, SEED_POSITION_MINT, SEED_SOL_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct OpenPosition<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = mint_account,
+    )]
+    pub config_account: Box<Account<'info, Config>>,
+
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = ANCHOR_DISCRIMINATOR + PositionCounter::INIT_SPACE,
+        seeds = [SEED_POSITION_COUNTER, depositor.key().as_ref()],
+        bump
+    )]
+    pub position_counter: Account<'info, PositionCounter>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = ANCHOR_DISCRIMINATOR + Collateral::INIT_SPACE,
+        seeds = [SEED_COLLATERAL_ACCOUNT, depositor.key().as_ref(), &position_id.to_le_bytes()],
+        bump
+    )]
+    pub collateral_account: Box<Account<'info, Collateral>>,
+
+    #[account(
+        seeds = [SEED_SOL_ACCOUNT, depositor.key().as_ref(), &position_id.to_le_bytes()],
+        bump
+    )]
+    pub sol_account: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint_account,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = depositor,
+        seeds = [SEED_POSITION_MINT, collateral_account.key().as_ref()],
+        bump,
+        mint::decimals = POSITION_MINT_DECIMALS,
+        mint::authority = position_mint,
+        mint::token_program = position_token_program
+    )]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = depositor,
+        associated_token::mint = position_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = position_token_program
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    // position NFTs are always Token-2022, whichever program the stablecoin uses
+    #[account(address = token_2022::ID)]
+    pub position_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, depositor.key().as_ref()],
+        bump,
+        constraint = depositor_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub depositor_blocklist_entry: UncheckedAccount<'info>,
+}
+
+/// open a new empty position and mint its NFT to the depositor
+/// # Arguments
+/// * `position_id` - Must be the depositor's next unused position id
+pub fn process_open_position(context: Context<OpenPosition>, position_id: u64) -> Result<()> {
+    let position_counter = &mut context.accounts.position_counter;
+    require!(
+        position_id == position_counter.next_position_id,
+        StablecointError::InvalidPositionId
+    );
+    position_counter.depositor = context.accounts.depositor.key();
+    position_counter.bump = context.bumps.position_counter;
+    position_counter.next_position_id = position_id
+        .checked_add(1)
+        .ok_or(StablecointError::MathError)?;
+
+    **context.accounts.collateral_account = Collateral {
+        depositor: context.accounts.depositor.key(),
+        creator: context.accounts.depositor.key(),
+        position_id,
+        position_mint: context.accounts.position_mint.key(),
+        pending_depositor: Pubkey::default(),
+        sol_account: context.accounts.sol_account.key(),
+        token_account: context.accounts.token_account.key(),
+        lamport_balance: 0,
+        amount_minted: 0,
+        bump: context.bumps.collateral_account,
+        bump_sol_account: context.bumps.sol_account,
+        bump_position_mint: context.bumps.position_mint,
+        is_initialized: true,
+    };
+
+    // mint exactly one token and drop the mint authority so the supply stays at 1
+    let collateral_key = context.accounts.collateral_account.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_POSITION_MINT,
+        collateral_key.as_ref(),
+        &[context.bumps.position_mint],
+    ]];
+    mint_tokens_with_pda_authority(
+        &context.accounts.position_mint,
+        &context.accounts.position_token_account,
+        &context.accounts.position_mint,
+        1,
+        &context.accounts.position_token_program,
+        signer_seeds,
+    )?;
+    remove_mint_authority(
+        &context.accounts.position_mint,
+        &context.accounts.position_mint,
+        &context.accounts.position_token_program,
+        signer_seeds,
+    )?;
+    Ok(())
+}
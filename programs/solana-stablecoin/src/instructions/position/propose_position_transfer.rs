@@ -0,0 +1,79 @@
+use crate::{
+    error::StablecointError, is_position_holder, state::Collateral, SEED_BLOCKLIST,
+    SEED_COLLATERAL_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{approve, revoke, Approve, Revoke, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ProposePositionTransfer<'info> {
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    #[account(
+        mut,
+        constraint = is_position_holder(&collateral_account, &position_token_account, &depositor.key())
+            @ StablecointError::NotPositionHolder
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, depositor.key().as_ref()],
+        bump,
+        constraint = depositor_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub depositor_blocklist_entry: UncheckedAccount<'info>,
+}
+
+/// first step of a position transfer, the new owner has to accept it
+///
+/// The collateral account is approved as delegate of the NFT, so accepting can move it
+/// without the current holder signing again. Moving the NFT away in the meantime drops
+/// the approval along with it.
+/// # Arguments
+/// * `new_depositor` - Wallet that may accept the position, `Pubkey::default()` cancels a pending transfer
+pub fn process_propose_position_transfer(
+    context: Context<ProposePositionTransfer>,
+    new_depositor: Pubkey,
+) -> Result<()> {
+    let accounts = &context.accounts;
+    if new_depositor == Pubkey::default() {
+        revoke(CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Revoke {
+                source: accounts.position_token_account.to_account_info(),
+                authority: accounts.depositor.to_account_info(),
+            },
+        ))?;
+    } else {
+        approve(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Approve {
+                    to: accounts.position_token_account.to_account_info(),
+                    delegate: accounts.collateral_account.to_account_info(),
+                    authority: accounts.depositor.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+    }
+
+    let collateral_account = &mut context.accounts.collateral_account;
+    collateral_account.depositor = context.accounts.depositor.key();
+    collateral_account.pending_depositor = new_depositor;
+    Ok(())
+}
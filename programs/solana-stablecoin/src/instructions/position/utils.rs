@@ -0,0 +1,14 @@
+use crate::state::Collateral;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+/// Whoever holds the position NFT in `position_token_account` manages the position.
+pub fn is_position_holder(
+    collateral: &Collateral,
+    position_token_account: &TokenAccount,
+    holder: &Pubkey,
+) -> bool {
+    position_token_account.mint == collateral.position_mint
+        && position_token_account.owner == *holder
+        && position_token_account.amount == 1
+}
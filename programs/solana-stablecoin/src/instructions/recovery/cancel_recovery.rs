@@ -0,0 +1,44 @@
+use crate::{
+    error::StablecointError,
+    events::RecoveryCancelled,
+    state::{Config, RecoveryRequest},
+    SEED_CONFIG_ACCOUNT, SEED_RECOVERY_REQUEST,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    // the admin authority can veto a recovery while it is timelocked
+    #[account(
+        constraint = canceller.key() == config_account.recovery_authority
+            || canceller.key() == config_account.authority
+            @ StablecointError::NotRecoveryCanceller
+    )]
+    pub canceller: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    /// CHECK: receives the rent of the recovery request
+    #[account(mut, address = config_account.recovery_authority)]
+    pub recovery_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_RECOVERY_REQUEST, recovery_request.token_account.as_ref()],
+        bump = recovery_request.bump,
+        close = recovery_authority
+    )]
+    pub recovery_request: Account<'info, RecoveryRequest>,
+}
+
+pub fn process_cancel_recovery(context: Context<CancelRecovery>) -> Result<()> {
+    emit!(RecoveryCancelled {
+        token_account: context.accounts.recovery_request.token_account,
+        cancelled_by: context.accounts.canceller.key(),
+    });
+    Ok(())
+}
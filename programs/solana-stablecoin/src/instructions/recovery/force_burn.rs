@@ -0,0 +1,88 @@
+use crate::{
+    error::StablecointError,
+    events::ForceBurned,
+    refreeze_after_recovery, require_recovery_due,
+    state::{Config, RecoveryRequest},
+    thaw_for_recovery, SEED_CONFIG_ACCOUNT, SEED_RECOVERY_REQUEST,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ForceBurn<'info> {
+    #[account(mut)]
+    pub recovery_authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = recovery_authority,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_RECOVERY_REQUEST, token_account.key().as_ref()],
+        bump = recovery_request.bump,
+        constraint = recovery_request.destination.is_none() @ StablecointError::RecoveryActionMismatch,
+        close = recovery_authority
+    )]
+    pub recovery_request: Account<'info, RecoveryRequest>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// the config PDA burns as the mint's permanent delegate
+pub fn process_force_burn(context: Context<ForceBurn>) -> Result<()> {
+    require_recovery_due(&context.accounts.recovery_request)?;
+
+    let config_account = &context.accounts.config_account;
+    let was_frozen = thaw_for_recovery(
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.token_account,
+        config_account.bump_mint_account,
+    )?;
+
+    let amount = context.accounts.recovery_request.amount;
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_CONFIG_ACCOUNT, &[config_account.bump]]];
+    burn(
+        CpiContext::new_with_signer(
+            context.accounts.token_program.to_account_info(),
+            Burn {
+                mint: context.accounts.mint_account.to_account_info(),
+                from: context.accounts.token_account.to_account_info(),
+                authority: config_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    if was_frozen {
+        refreeze_after_recovery(
+            &context.accounts.token_program,
+            &context.accounts.mint_account,
+            &context.accounts.token_account,
+            config_account.bump_mint_account,
+        )?;
+    }
+
+    emit!(ForceBurned {
+        token_account: context.accounts.token_account.key(),
+        amount,
+    });
+    Ok(())
+}
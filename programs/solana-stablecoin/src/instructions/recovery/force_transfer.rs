@@ -0,0 +1,100 @@
+use crate::{
+    error::StablecointError,
+    events::ForceTransferred,
+    refreeze_after_recovery, require_recovery_due,
+    state::{Config, RecoveryRequest},
+    thaw_for_recovery, SEED_CONFIG_ACCOUNT, SEED_RECOVERY_REQUEST,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ForceTransfer<'info> {
+    #[account(mut)]
+    pub recovery_authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = recovery_authority,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::token_program = token_program
+    )]
+    pub source_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::token_program = token_program
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_RECOVERY_REQUEST, source_token_account.key().as_ref()],
+        bump = recovery_request.bump,
+        constraint = recovery_request.destination == Some(destination_token_account.key())
+            @ StablecointError::RecoveryActionMismatch,
+        close = recovery_authority
+    )]
+    pub recovery_request: Account<'info, RecoveryRequest>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// move the scheduled amount to the recovery destination as the permanent delegate
+///
+/// The transfer-hook accounts are passed as remaining accounts, like for savings.
+pub fn process_force_transfer<'info>(
+    context: Context<'_, '_, '_, 'info, ForceTransfer<'info>>,
+) -> Result<()> {
+    require_recovery_due(&context.accounts.recovery_request)?;
+
+    let config_account = &context.accounts.config_account;
+    let was_frozen = thaw_for_recovery(
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.source_token_account,
+        config_account.bump_mint_account,
+    )?;
+
+    let amount = context.accounts.recovery_request.amount;
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_CONFIG_ACCOUNT, &[config_account.bump]]];
+    invoke_transfer_checked(
+        context.accounts.token_program.key,
+        context.accounts.source_token_account.to_account_info(),
+        context.accounts.mint_account.to_account_info(),
+        context.accounts.destination_token_account.to_account_info(),
+        config_account.to_account_info(),
+        context.remaining_accounts,
+        amount,
+        context.accounts.mint_account.decimals,
+        signer_seeds,
+    )?;
+
+    if was_frozen {
+        refreeze_after_recovery(
+            &context.accounts.token_program,
+            &context.accounts.mint_account,
+            &context.accounts.source_token_account,
+            config_account.bump_mint_account,
+        )?;
+    }
+
+    emit!(ForceTransferred {
+        source: context.accounts.source_token_account.key(),
+        destination: context.accounts.destination_token_account.key(),
+        amount,
+    });
+    Ok(())
+}
@@ -0,0 +1,10 @@
+pub mod cancel_recovery;
+pub use cancel_recovery::*;
+pub mod force_burn;
+pub use force_burn::*;
+pub mod force_transfer;
+pub use force_transfer::*;
+pub mod schedule_recovery;
+pub use schedule_recovery::*;
+pub mod utils;
+pub use utils::*;
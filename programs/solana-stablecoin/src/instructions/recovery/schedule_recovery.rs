@@ -0,0 +1,75 @@
+use crate::{
+    error::StablecointError,
+    events::RecoveryScheduled,
+    state::{Config, RecoveryRequest},
+    ANCHOR_DISCRIMINATOR, SEED_CONFIG_ACCOUNT, SEED_RECOVERY_REQUEST,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ScheduleRecovery<'info> {
+    #[account(mut)]
+    pub recovery_authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = recovery_authority,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint_account)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = recovery_authority,
+        space = ANCHOR_DISCRIMINATOR + RecoveryRequest::INIT_SPACE,
+        seeds = [SEED_RECOVERY_REQUEST, token_account.key().as_ref()],
+        bump
+    )]
+    pub recovery_request: Account<'info, RecoveryRequest>,
+    pub system_program: Program<'info, System>,
+}
+
+/// queue a forced burn or transfer, executable once the timelock has passed
+/// # Arguments
+/// * `amount` - Stablecoin to take from the token account
+/// * `destination` - Token account to send it to, `None` burns it instead
+pub fn process_schedule_recovery(
+    context: Context<ScheduleRecovery>,
+    amount: u64,
+    destination: Option<Pubkey>,
+) -> Result<()> {
+    // only Token-2022 mints carry the permanent delegate
+    require_keys_eq!(
+        context.accounts.config_account.token_program,
+        Token2022::id(),
+        StablecointError::TokenExtensionsUnsupported
+    );
+
+    let executable_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(context.accounts.config_account.recovery_timelock)
+        .ok_or(StablecointError::MathError)?;
+    let token_account = context.accounts.token_account.key();
+    *context.accounts.recovery_request = RecoveryRequest {
+        token_account,
+        destination,
+        amount,
+        executable_at,
+        bump: context.bumps.recovery_request,
+    };
+
+    emit!(RecoveryScheduled {
+        token_account,
+        destination,
+        amount,
+        executable_at,
+    });
+    Ok(())
+}
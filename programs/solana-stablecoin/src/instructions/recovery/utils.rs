@@ -0,0 +1,56 @@
+use crate::{error::StablecointError, state::RecoveryRequest, SEED_MINT_ACCOUNT};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    freeze_account, thaw_account, FreezeAccount, Mint, ThawAccount, TokenAccount, TokenInterface,
+};
+
+pub fn require_recovery_due(recovery_request: &RecoveryRequest) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= recovery_request.executable_at,
+        StablecointError::RecoveryTimelockActive
+    );
+    Ok(())
+}
+
+/// Thaws `token_account` if compliance froze it, returning whether it has to be frozen again.
+///
+/// Token-2022 refuses to move funds out of a frozen account, even for the permanent delegate.
+pub fn thaw_for_recovery<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint_account: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    bump_mint_account: u8,
+) -> Result<bool> {
+    if !token_account.is_frozen() {
+        return Ok(false);
+    }
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_MINT_ACCOUNT, &[bump_mint_account]]];
+    thaw_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        ThawAccount {
+            account: token_account.to_account_info(),
+            mint: mint_account.to_account_info(),
+            authority: mint_account.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    Ok(true)
+}
+
+pub fn refreeze_after_recovery<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint_account: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    bump_mint_account: u8,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_MINT_ACCOUNT, &[bump_mint_account]]];
+    freeze_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        FreezeAccount {
+            account: token_account.to_account_info(),
+            mint: mint_account.to_account_info(),
+            authority: mint_account.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
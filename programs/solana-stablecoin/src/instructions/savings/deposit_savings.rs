@@ -0,0 +1,105 @@
+use crate::{
+    amount_to_shares, drip_savings_vault,
+    error::StablecointError,
+    state::{Config, SavingsPosition, SavingsVault},
+    transfer_to_savings_vault, ANCHOR_DISCRIMINATOR, SEED_CONFIG_ACCOUNT, SEED_SAVINGS_POSITION,
+    SEED_SAVINGS_TOKEN_ACCOUNT, SEED_SAVINGS_VAULT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct DepositSavings<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_VAULT],
+        bump = savings_vault.bump,
+    )]
+    pub savings_vault: Account<'info, SavingsVault>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_TOKEN_ACCOUNT],
+        bump = savings_vault.bump_token_account,
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = ANCHOR_DISCRIMINATOR + SavingsPosition::INIT_SPACE,
+        seeds = [SEED_SAVINGS_POSITION, depositor.key().as_ref()],
+        bump
+    )]
+    pub savings_position: Account<'info, SavingsPosition>,
+
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::authority = depositor,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// deposit stablecoin into the savings vault in exchange for shares
+/// # Arguments
+/// * `amount` - Amount of stablecoin to deposit
+pub fn process_deposit_savings<'info>(
+    context: Context<'_, '_, '_, 'info, DepositSavings<'info>>,
+    amount: u64,
+) -> Result<()> {
+    drip_savings_vault(
+        &mut context.accounts.savings_vault,
+        context.accounts.config_account.bump_mint_account,
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.savings_token_account,
+    )?;
+
+    // shares round down so the depositor never gets more than they paid for
+    let shares = amount_to_shares(amount, context.accounts.savings_vault.exchange_rate)?;
+    require!(shares > 0, StablecointError::ZeroSavingsShares);
+
+    let savings_vault = &mut context.accounts.savings_vault;
+    savings_vault.total_shares = savings_vault
+        .total_shares
+        .checked_add(shares)
+        .ok_or(StablecointError::MathError)?;
+
+    let savings_position = &mut context.accounts.savings_position;
+    savings_position.owner = context.accounts.depositor.key();
+    savings_position.bump = context.bumps.savings_position;
+    savings_position.shares = savings_position
+        .shares
+        .checked_add(shares)
+        .ok_or(StablecointError::MathError)?;
+
+    transfer_to_savings_vault(
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.token_account,
+        &context.accounts.savings_token_account,
+        &context.accounts.depositor,
+        context.remaining_accounts,
+        amount,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use crate::{
+    drip_savings_vault,
+    error::StablecointError,
+    events::KeeperTipPaid,
+    pay_treasury_tip,
+    state::{Config, SavingsVault},
+    ACCRUAL_TIP_INTERVAL, SEED_BLOCKLIST, SEED_CONFIG_ACCOUNT, SEED_SAVINGS_TOKEN_ACCOUNT,
+    SEED_SAVINGS_VAULT, SEED_TREASURY,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct DripSavings<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_VAULT],
+        bump = savings_vault.bump,
+    )]
+    pub savings_vault: Account<'info, SavingsVault>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_TOKEN_ACCOUNT],
+        bump = savings_vault.bump_token_account,
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, keeper.key().as_ref()],
+        bump,
+        constraint = keeper_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub keeper_blocklist_entry: UncheckedAccount<'info>,
+}
+
+// anyone can call this to bring the exchange rate up to date, and is tipped from the
+// treasury when the vault had gone untouched for a while and something was dripped
+pub fn process_drip_savings(context: Context<DripSavings>) -> Result<()> {
+    let elapsed = Clock::get()?
+        .unix_timestamp
+        .saturating_sub(context.accounts.savings_vault.last_update_timestamp);
+    let amount_dripped = drip_savings_vault(
+        &mut context.accounts.savings_vault,
+        context.accounts.config_account.bump_mint_account,
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.savings_token_account,
+    )?;
+    msg!("Dripped {} to the savings vault", amount_dripped);
+
+    if amount_dripped > 0 && elapsed >= ACCRUAL_TIP_INTERVAL {
+        let tip = pay_treasury_tip(
+            &context.accounts.treasury,
+            &context.accounts.keeper.to_account_info(),
+            &context.accounts.system_program,
+            context.bumps.treasury,
+            context.accounts.config_account.keeper_tip,
+        )?;
+        if tip > 0 {
+            emit!(KeeperTipPaid {
+                keeper: context.accounts.keeper.key(),
+                source: context.accounts.treasury.key(),
+                amount: tip,
+            });
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::{
+    error::StablecointError,
+    state::{Config, SavingsVault},
+    ANCHOR_DISCRIMINATOR, SAVINGS_EXCHANGE_RATE_PRECISION, SEED_CONFIG_ACCOUNT,
+    SEED_SAVINGS_TOKEN_ACCOUNT, SEED_SAVINGS_VAULT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct InitializeSavingsVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = authority,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + SavingsVault::INIT_SPACE,
+        seeds = [SEED_SAVINGS_VAULT],
+        bump
+    )]
+    pub savings_vault: Account<'info, SavingsVault>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [SEED_SAVINGS_TOKEN_ACCOUNT],
+        bump,
+        token::mint = mint_account,
+        token::authority = savings_vault,
+        token::token_program = token_program
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// create the savings vault
+/// # Arguments
+/// * `savings_rate_bps` - Yearly savings rate in basis points
+/// * `mint_budget` - Most stablecoin the program may mint to pay savings
+pub fn process_initialize_savings_vault(
+    context: Context<InitializeSavingsVault>,
+    savings_rate_bps: u64,
+    mint_budget: u64,
+) -> Result<()> {
+    *context.accounts.savings_vault = SavingsVault {
+        token_account: context.accounts.savings_token_account.key(),
+        savings_rate_bps,
+        mint_budget,
+        total_dripped: 0,
+        total_shares: 0,
+        exchange_rate: SAVINGS_EXCHANGE_RATE_PRECISION,
+        last_update_timestamp: Clock::get()?.unix_timestamp,
+        bump: context.bumps.savings_vault,
+        bump_token_account: context.bumps.savings_token_account,
+    };
+    Ok(())
+}
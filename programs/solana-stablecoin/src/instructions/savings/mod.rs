@@ -0,0 +1,12 @@
+pub mod deposit_savings;
+pub use deposit_savings::*;
+pub mod drip_savings;
+pub use drip_savings::*;
+pub mod initialize_savings_vault;
+pub use initialize_savings_vault::*;
+pub mod update_savings_vault;
+pub use update_savings_vault::*;
+pub mod utils;
+pub use utils::*;
+pub mod withdraw_savings;
+pub use withdraw_savings::*;
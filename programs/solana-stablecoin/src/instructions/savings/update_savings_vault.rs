@@ -0,0 +1,65 @@
+use crate::{
+    drip_savings_vault,
+    error::StablecointError,
+    state::{Config, SavingsVault},
+    SEED_CONFIG_ACCOUNT, SEED_SAVINGS_TOKEN_ACCOUNT, SEED_SAVINGS_VAULT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct UpdateSavingsVault<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = authority,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_VAULT],
+        bump = savings_vault.bump,
+    )]
+    pub savings_vault: Account<'info, SavingsVault>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_TOKEN_ACCOUNT],
+        bump = savings_vault.bump_token_account,
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_update_savings_vault(
+    context: Context<UpdateSavingsVault>,
+    savings_rate_bps: u64,
+    mint_budget: u64,
+) -> Result<()> {
+    // settle what was earned at the old rate before switching to the new one
+    drip_savings_vault(
+        &mut context.accounts.savings_vault,
+        context.accounts.config_account.bump_mint_account,
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.savings_token_account,
+    )?;
+
+    let savings_vault = &mut context.accounts.savings_vault;
+    require!(
+        mint_budget >= savings_vault.total_dripped,
+        StablecointError::InvalidSavingsBudget
+    );
+    savings_vault.savings_rate_bps = savings_rate_bps;
+    savings_vault.mint_budget = mint_budget;
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use crate::{
+    error::StablecointError, mint_tokens, state::SavingsVault, BASIS_POINTS,
+    SAVINGS_EXCHANGE_RATE_PRECISION, SECONDS_PER_YEAR, SEED_SAVINGS_VAULT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Brings the share exchange rate up to `now` and returns the amount of stablecoin
+/// that has to be minted to the vault to back the increase.
+///
+/// Interest is simple between updates and is capped so that the vault never mints
+/// more than its remaining budget.
+pub fn accrue_savings(savings_vault: &mut SavingsVault, now: i64) -> Result<u64> {
+    let elapsed = now.saturating_sub(savings_vault.last_update_timestamp);
+    if elapsed <= 0 {
+        return Ok(0);
+    }
+    savings_vault.last_update_timestamp = now;
+
+    if savings_vault.total_shares == 0 || savings_vault.savings_rate_bps == 0 {
+        return Ok(0);
+    }
+
+    let mut rate_increase = savings_vault
+        .exchange_rate
+        .checked_mul(savings_vault.savings_rate_bps as u128)
+        .and_then(|n| n.checked_mul(elapsed as u128))
+        .and_then(|n| n.checked_div(BASIS_POINTS as u128 * SECONDS_PER_YEAR as u128))
+        .ok_or(StablecointError::MathError)?;
+    let mut amount_to_drip = (savings_vault.total_shares as u128)
+        .checked_mul(rate_increase)
+        .and_then(|n| n.checked_div(SAVINGS_EXCHANGE_RATE_PRECISION))
+        .ok_or(StablecointError::MathError)?;
+
+    // once the budget runs out the rate only grows by what the remaining budget can back
+    let remaining_budget = savings_vault
+        .mint_budget
+        .saturating_sub(savings_vault.total_dripped) as u128;
+    if amount_to_drip > remaining_budget {
+        amount_to_drip = remaining_budget;
+        rate_increase = remaining_budget
+            .checked_mul(SAVINGS_EXCHANGE_RATE_PRECISION)
+            .and_then(|n| n.checked_div(savings_vault.total_shares as u128))
+            .ok_or(StablecointError::MathError)?;
+    }
+
+    let amount_to_drip = u64::try_from(amount_to_drip).map_err(|_| StablecointError::MathError)?;
+    savings_vault.exchange_rate = savings_vault
+        .exchange_rate
+        .checked_add(rate_increase)
+        .ok_or(StablecointError::MathError)?;
+    savings_vault.total_dripped = savings_vault
+        .total_dripped
+        .checked_add(amount_to_drip)
+        .ok_or(StablecointError::MathError)?;
+    Ok(amount_to_drip)
+}
+
+/// Accrues the vault and mints whatever the accrual requires into the vault token account.
+pub fn drip_savings_vault<'info>(
+    savings_vault: &mut Account<'info, SavingsVault>,
+    bump_mint_account: u8,
+    token_program: &Interface<'info, TokenInterface>,
+    mint_account: &InterfaceAccount<'info, Mint>,
+    savings_token_account: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<u64> {
+    let amount_to_drip = accrue_savings(savings_vault, Clock::get()?.unix_timestamp)?;
+    if amount_to_drip > 0 {
+        mint_tokens(
+            bump_mint_account,
+            token_program,
+            mint_account,
+            savings_token_account,
+            amount_to_drip,
+        )?;
+    }
+    Ok(amount_to_drip)
+}
+
+pub fn shares_to_amount(shares: u64, exchange_rate: u128) -> Result<u64> {
+    let amount = (shares as u128)
+        .checked_mul(exchange_rate)
+        .and_then(|n| n.checked_div(SAVINGS_EXCHANGE_RATE_PRECISION))
+        .ok_or(StablecointError::MathError)?;
+    u64::try_from(amount).map_err(|_| error!(StablecointError::MathError))
+}
+
+pub fn amount_to_shares(amount: u64, exchange_rate: u128) -> Result<u64> {
+    let shares = (amount as u128)
+        .checked_mul(SAVINGS_EXCHANGE_RATE_PRECISION)
+        .and_then(|n| n.checked_div(exchange_rate))
+        .ok_or(StablecointError::MathError)?;
+    u64::try_from(shares).map_err(|_| error!(StablecointError::MathError))
+}
+
+/// Moves stablecoin from a holder into the savings vault.
+///
+/// A Token-2022 mint carries a TransferHook, so the hook's extra accounts have to be
+/// forwarded with the transfer. They are empty for a legacy SPL Token mint.
+/// # Arguments
+/// * `additional_accounts` - the resolved transfer-hook accounts, in order
+pub fn transfer_to_savings_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint_account: &InterfaceAccount<'info, Mint>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    authority: &Signer<'info>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    invoke_transfer_checked(
+        token_program.key,
+        from.to_account_info(),
+        mint_account.to_account_info(),
+        to.to_account_info(),
+        authority.to_account_info(),
+        additional_accounts,
+        amount,
+        mint_account.decimals,
+        &[],
+    )
+    .map_err(Into::into)
+}
+
+/// Moves stablecoin out of the savings vault, signed by the vault PDA.
+/// # Arguments
+/// * `additional_accounts` - the resolved transfer-hook accounts, in order
+pub fn transfer_from_savings_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint_account: &InterfaceAccount<'info, Mint>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    savings_vault: &Account<'info, SavingsVault>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_SAVINGS_VAULT, &[savings_vault.bump]]];
+    invoke_transfer_checked(
+        token_program.key,
+        from.to_account_info(),
+        mint_account.to_account_info(),
+        to.to_account_info(),
+        savings_vault.to_account_info(),
+        additional_accounts,
+        amount,
+        mint_account.decimals,
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
@@ -0,0 +1,102 @@
+use crate::{
+    drip_savings_vault,
+    error::StablecointError,
+    shares_to_amount,
+    state::{Config, SavingsPosition, SavingsVault},
+    transfer_from_savings_vault, SEED_CONFIG_ACCOUNT, SEED_SAVINGS_POSITION,
+    SEED_SAVINGS_TOKEN_ACCOUNT, SEED_SAVINGS_VAULT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct WithdrawSavings<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_VAULT],
+        bump = savings_vault.bump,
+    )]
+    pub savings_vault: Account<'info, SavingsVault>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_TOKEN_ACCOUNT],
+        bump = savings_vault.bump_token_account,
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SAVINGS_POSITION, depositor.key().as_ref()],
+        bump = savings_position.bump,
+    )]
+    pub savings_position: Account<'info, SavingsPosition>,
+
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// redeem savings shares for stablecoin at the current exchange rate
+/// # Arguments
+/// * `shares` - Amount of shares to redeem
+pub fn process_withdraw_savings<'info>(
+    context: Context<'_, '_, '_, 'info, WithdrawSavings<'info>>,
+    shares: u64,
+) -> Result<()> {
+    drip_savings_vault(
+        &mut context.accounts.savings_vault,
+        context.accounts.config_account.bump_mint_account,
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.savings_token_account,
+    )?;
+
+    let savings_position = &mut context.accounts.savings_position;
+    require!(
+        shares <= savings_position.shares,
+        StablecointError::InsufficientSavingsShares
+    );
+    savings_position.shares -= shares;
+
+    let savings_vault = &mut context.accounts.savings_vault;
+    savings_vault.total_shares = savings_vault
+        .total_shares
+        .checked_sub(shares)
+        .ok_or(StablecointError::MathError)?;
+
+    // rounding dust can leave the vault a few units short of the last withdrawal
+    context.accounts.savings_token_account.reload()?;
+    let amount = shares_to_amount(shares, context.accounts.savings_vault.exchange_rate)?
+        .min(context.accounts.savings_token_account.amount);
+
+    transfer_from_savings_vault(
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.savings_token_account,
+        &context.accounts.token_account,
+        &context.accounts.savings_vault,
+        context.remaining_accounts,
+        amount,
+    )?;
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use crate::{
-    error::StablecointError, Collateral, Config, MAXIMUM_AGE, PRICE_FEED_DECIMAL_ADJUSTMENT,
-    SOL_USD_FEED_ID,
+    error::StablecointError,
+    helpers::transfer_sol_from_pda,
+    math::{self, OraclePrice},
+    Collateral, Config, MAXIMUM_AGE, SEED_TREASURY, SOL_USD_FEED_ID,
 };
-use anchor_lang::{prelude::*, solana_program::native_token::LAMPORTS_PER_SOL};
+use anchor_lang::prelude::*;
 use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
 
 pub fn check_health_factor(
@@ -24,62 +26,54 @@ pub fn calculate_health_factor(
     config: &Account<Config>,
     price_feed: &Account<PriceUpdateV2>,
 ) -> Result<u64> {
-    // health factor = collateral adjusted for the liquidation threshold / amount minted
-    let collateral_value_in_usd = get_usd_value(&collateral.lamport_balance, price_feed)?;
-    let numerator = collateral_value_in_usd
-        .checked_mul(config.liquidation_threshold)
-        .ok_or(StablecointError::MathError)?;
-    // we are dividing by 100 because it is %
-    let collateral_adjusted_for_liquidation_threshold = numerator
-        .checked_div(100)
-        .ok_or(StablecointError::MathError)?;
-    // health factor = collateral adjusted for liquidation threshold / amount minted;
-    if collateral.amount_minted == 0 {
+    let health_factor = math::health_factor(
+        collateral.lamport_balance,
+        collateral.amount_minted,
+        config.liquidation_threshold,
+        get_sol_usd_price(price_feed)?,
+    )?;
+    if health_factor == u64::MAX {
         msg!("Health Factor Max");
-        return Ok(u64::MAX);
     }
-    let health_factor = collateral_adjusted_for_liquidation_threshold
-        .checked_div(collateral.amount_minted)
-        .ok_or(StablecointError::MathError)?;
     Ok(health_factor)
 }
 
-pub fn get_usd_value(amount_in_lamports: &u64, price_feed: &Account<PriceUpdateV2>) -> Result<u64> {
+/// the SOL/USD price, rejected when older than `MAXIMUM_AGE`
+pub fn get_sol_usd_price(price_feed: &Account<PriceUpdateV2>) -> Result<OraclePrice> {
     let feed_id = get_feed_id_from_hex(SOL_USD_FEED_ID)?;
     let price = price_feed.get_price_no_older_than(&Clock::get()?, MAXIMUM_AGE, &feed_id)?;
-    require!(price.price > 0, StablecointError::InvalidPrice);
-    // the price feed returns 10^8 so we want to multiply by 10 to reach 10^9 so that the precision will be in lamports
-    let price_as_u128 = price.price as u128;
-    let price_in_usd = price_as_u128
-        .checked_mul(PRICE_FEED_DECIMAL_ADJUSTMENT)
-        .ok_or(StablecointError::MathError)?;
-    // this amount is in lamports precision
-    let amount_in_usd = (*amount_in_lamports as u128 * price_in_usd) / (LAMPORTS_PER_SOL as u128);
-    Ok(amount_in_usd as u64)
+    Ok(price.into())
+}
+
+pub fn get_usd_value(amount_in_lamports: &u64, price_feed: &Account<PriceUpdateV2>) -> Result<u64> {
+    math::usd_value(*amount_in_lamports, get_sol_usd_price(price_feed)?)
 }
 
 pub fn get_lamports_from_usd(
     amount_in_usd: &u64,
     price_feed: &Account<PriceUpdateV2>,
 ) -> Result<u64> {
-    let feed_id = get_feed_id_from_hex(SOL_USD_FEED_ID)?;
-    let price = price_feed.get_price_no_older_than(&Clock::get()?, MAXIMUM_AGE, &feed_id)?;
-
-    require!(price.price > 0, StablecointError::InvalidPrice);
-    let price_as_u128 = price.price as u128;
-    let amount_in_usd_u128 = *amount_in_usd as u128;
-
-    let price_in_usd = price_as_u128
-        .checked_mul(PRICE_FEED_DECIMAL_ADJUSTMENT)
-        .ok_or(StablecointError::MathError)?;
-
-    let numerator = amount_in_usd_u128
-        .checked_mul(LAMPORTS_PER_SOL as u128)
-        .ok_or(StablecointError::MathError)?;
-
-    let amount_in_lamports = numerator
-        .checked_div(price_in_usd)
-        .ok_or(StablecointError::MathError)?;
+    math::lamports_from_usd(*amount_in_usd, get_sol_usd_price(price_feed)?)
+}
 
-    Ok(amount_in_lamports as u64)
+/// Pays up to `tip` lamports from the treasury to `keeper` and returns what was paid.
+///
+/// The treasury is a system account funded by plain transfers, it pays what it can
+/// while staying rent exempt and pays nothing once it runs dry.
+pub fn pay_treasury_tip<'info>(
+    treasury: &SystemAccount<'info>,
+    keeper: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    bump: u8,
+    tip: u64,
+) -> Result<u64> {
+    let available = treasury
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    let amount = tip.min(available);
+    if amount > 0 {
+        let signer_seeds: &[&[&[u8]]] = &[&[SEED_TREASURY, &[bump]]];
+        transfer_sol_from_pda(treasury, keeper, amount, system_program, signer_seeds)?;
+    }
+    Ok(amount)
 }
@@ -0,0 +1,29 @@
+use crate::{get_sol_usd_price, math, ViewPosition};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LiquidationPrice {
+    // the position can be liquidated below `price * 10^exponent` dollars per SOL,
+    // 0 when it never can and i64::MAX when it already can at any price
+    pub price: i64,
+    pub exponent: i32,
+}
+
+/// SOL/USD price below which the position can be liquidated, in the feed's exponent
+pub fn process_get_liquidation_price(context: Context<ViewPosition>) -> Result<LiquidationPrice> {
+    let collateral = &context.accounts.collateral_account;
+    let config = &context.accounts.config_account;
+    // the current price only provides the exponent and rejects stale feeds
+    let exponent = get_sol_usd_price(&context.accounts.price_update)?.exponent;
+
+    Ok(LiquidationPrice {
+        price: math::liquidation_price(
+            collateral.lamport_balance,
+            collateral.amount_minted,
+            config.liquidation_threshold,
+            config.min_health_factor,
+            exponent,
+        )?,
+        exponent,
+    })
+}
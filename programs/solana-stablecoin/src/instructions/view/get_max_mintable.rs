@@ -0,0 +1,16 @@
+use crate::{get_sol_usd_price, math, ViewPosition};
+use anchor_lang::prelude::*;
+
+/// stablecoin that can still be minted against the position without adding collateral
+pub fn process_get_max_mintable(context: Context<ViewPosition>) -> Result<u64> {
+    let collateral = &context.accounts.collateral_account;
+    let config = &context.accounts.config_account;
+
+    let max_debt = math::max_debt(
+        collateral.lamport_balance,
+        config.liquidation_threshold,
+        config.min_health_factor,
+        get_sol_usd_price(&context.accounts.price_update)?,
+    )?;
+    Ok(max_debt.saturating_sub(collateral.amount_minted))
+}
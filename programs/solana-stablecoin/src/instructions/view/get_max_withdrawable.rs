@@ -0,0 +1,16 @@
+use crate::{get_sol_usd_price, math, ViewPosition};
+use anchor_lang::prelude::*;
+
+/// lamports that can be redeemed from the position without burning stablecoin
+pub fn process_get_max_withdrawable(context: Context<ViewPosition>) -> Result<u64> {
+    let collateral = &context.accounts.collateral_account;
+    let config = &context.accounts.config_account;
+
+    math::max_withdrawable(
+        collateral.lamport_balance,
+        collateral.amount_minted,
+        config.liquidation_threshold,
+        config.min_health_factor,
+        get_sol_usd_price(&context.accounts.price_update)?,
+    )
+}
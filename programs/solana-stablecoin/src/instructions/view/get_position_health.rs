@@ -0,0 +1,30 @@
+use crate::{get_sol_usd_price, math, ViewPosition};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PositionHealth {
+    pub health_factor: u64, // u64::MAX when nothing is minted
+    pub collateral_value: u64,
+    pub amount_minted: u64,
+    pub liquidatable: bool,
+}
+
+/// health of the position at the current price
+pub fn process_get_position_health(context: Context<ViewPosition>) -> Result<PositionHealth> {
+    let collateral = &context.accounts.collateral_account;
+    let config = &context.accounts.config_account;
+    let price = get_sol_usd_price(&context.accounts.price_update)?;
+
+    let health_factor = math::health_factor(
+        collateral.lamport_balance,
+        collateral.amount_minted,
+        config.liquidation_threshold,
+        price,
+    )?;
+    Ok(PositionHealth {
+        health_factor,
+        collateral_value: math::usd_value(collateral.lamport_balance, price)?,
+        amount_minted: collateral.amount_minted,
+        liquidatable: math::is_liquidatable(health_factor, config.min_health_factor),
+    })
+}
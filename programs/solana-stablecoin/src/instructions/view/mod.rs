@@ -0,0 +1,10 @@
+pub mod get_liquidation_price;
+pub use get_liquidation_price::*;
+pub mod get_max_mintable;
+pub use get_max_mintable::*;
+pub mod get_max_withdrawable;
+pub use get_max_withdrawable::*;
+pub mod get_position_health;
+pub use get_position_health::*;
+pub mod utils;
+pub use utils::*;
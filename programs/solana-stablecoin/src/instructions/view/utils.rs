@@ -0,0 +1,29 @@
+use crate::{
+    state::{Collateral, Config},
+    SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
+};
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+// view instructions change nothing, they are meant to be run with `simulateTransaction`
+// and read back through the return data
+#[derive(Accounts)]
+pub struct ViewPosition<'info> {
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
+        bump = collateral_account.bump,
+    )]
+    pub collateral_account: Account<'info, Collateral>,
+
+    pub price_update: Account<'info, PriceUpdateV2>,
+}
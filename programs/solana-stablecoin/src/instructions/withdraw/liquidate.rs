@@ -1,12 +1,12 @@
 use crate::{
-    burn_tokens, check_health_factor, collateral,
+    burn_tokens, calculate_health_factor, check_health_factor,
     error::StablecointError,
-    get_lamports_from_usd,
+    get_sol_usd_price, math,
     state::{Collateral, Config},
-    withdraw_sol, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
+    vault_withdrawal, withdraw_sol, SEED_BLOCKLIST, SEED_CONFIG_ACCOUNT,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 #[derive(Accounts)]
@@ -41,42 +41,56 @@ pub struct Liquidate<'info> {
         associated_token::token_program = token_program
     )]
     pub token_account: InterfaceAccount<'info, TokenAccount>,
-    pub token_program: Program<'info, Token2022>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, liquidator.key().as_ref()],
+        bump,
+        constraint = liquidator_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub liquidator_blocklist_entry: UncheckedAccount<'info>,
 }
 
 /// liquidate the collateral account
 /// # Arguments
 /// * `amount_to_burn` - Amount to burn in usd
 pub fn process_liquidate(context: Context<Liquidate>, amount_to_burn: u64) -> Result<()> {
-    let health_factor = check_health_factor(
+    // only unhealthy positions can be liquidated, so the minimum isn't enforced here
+    let health_factor = calculate_health_factor(
         &context.accounts.collateral_account,
         &context.accounts.config_account,
         &context.accounts.price_update,
     )?;
 
-    // this line will never get hit since the health factor check will error if it is below the minimum
     require!(
         health_factor < context.accounts.config_account.min_health_factor,
         StablecointError::AboveMinimumHealthFactor
     );
 
-    let lamports = get_lamports_from_usd(&amount_to_burn, &context.accounts.price_update)?;
-    let liquidation_bonus = lamports
-        .checked_mul(context.accounts.config_account.liquidation_bonus)
-        .and_then(|n| n.checked_div(100))
-        .ok_or(StablecointError::MathError)?;
-    let amount_to_liquidate = lamports
-        .checked_add(liquidation_bonus)
-        .ok_or(StablecointError::MathError)?;
+    let outcome = math::liquidate_position(
+        context.accounts.collateral_account.lamport_balance,
+        context.accounts.collateral_account.amount_minted,
+        amount_to_burn,
+        context.accounts.config_account.liquidation_bonus,
+        get_sol_usd_price(&context.accounts.price_update)?,
+    )?;
 
+    let lamports_out = vault_withdrawal(
+        &context.accounts.sol_account,
+        outcome.lamports_paid,
+        outcome.lamport_balance,
+    )?;
     withdraw_sol(
         &context.accounts.sol_account,
         &context.accounts.liquidator,
         &context.accounts.system_program,
-        &context.accounts.collateral_account.depositor,
+        &context.accounts.collateral_account.creator,
+        context.accounts.collateral_account.position_id,
         context.accounts.collateral_account.bump_sol_account,
-        amount_to_liquidate,
+        lamports_out,
     )?;
 
     burn_tokens(
@@ -88,12 +102,8 @@ pub fn process_liquidate(context: Context<Liquidate>, amount_to_burn: u64) -> Re
     )?;
 
     let collateral_account = &mut context.accounts.collateral_account;
-    // the liquidation already occured so the current amount of lamports is correct
-    collateral_account.lamport_balance = context.accounts.sol_account.lamports();
-    collateral_account.amount_minted = collateral_account
-        .amount_minted
-        .checked_sub(amount_to_burn)
-        .ok_or(StablecointError::MathError)?;
+    collateral_account.lamport_balance = outcome.lamport_balance;
+    collateral_account.amount_minted = outcome.amount_minted;
 
     check_health_factor(
         &context.accounts.collateral_account,
@@ -0,0 +1,177 @@
+use crate::{
+    burn_tokens,
+    error::StablecointError,
+    get_sol_usd_price, math,
+    state::{Collateral, Config},
+    vault_withdrawal, withdraw_sol, SEED_BLOCKLIST, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
+    SEED_SOL_ACCOUNT,
+};
+use anchor_lang::{error::ErrorCode, prelude::*};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+#[derive(Accounts)]
+pub struct LiquidateBatch<'info> {
+    #[account(mut)]
+    pub liquidator: Signer<'info>,
+    pub price_update: Account<'info, PriceUpdateV2>,
+
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = config_account.bump,
+        has_one = mint_account,
+    )]
+    pub config_account: Account<'info, Config>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = liquidator,
+        associated_token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, liquidator.key().as_ref()],
+        bump,
+        constraint = liquidator_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub liquidator_blocklist_entry: UncheckedAccount<'info>,
+}
+
+/// liquidate several collateral accounts at one price and burn the total in one go
+/// # Arguments
+/// * `amounts_to_burn` - Amount to burn in usd for each position, the remaining accounts
+///   hold a collateral account and its sol account for each of them
+pub fn process_liquidate_batch<'info>(
+    context: Context<'_, '_, 'info, 'info, LiquidateBatch<'info>>,
+    amounts_to_burn: Vec<u64>,
+) -> Result<()> {
+    let remaining_accounts = context.remaining_accounts;
+    require!(
+        !amounts_to_burn.is_empty() && remaining_accounts.len() == amounts_to_burn.len() * 2,
+        StablecointError::InvalidLiquidationBatch
+    );
+
+    let config_account = &context.accounts.config_account;
+    let price = get_sol_usd_price(&context.accounts.price_update)?;
+    let mut total_to_burn: u64 = 0;
+
+    for (accounts, &amount_to_burn) in remaining_accounts.chunks(2).zip(&amounts_to_burn) {
+        let mut collateral_account = load_collateral(&accounts[0])?;
+        let sol_account = load_sol_account(&accounts[1], &collateral_account)?;
+
+        // only unhealthy positions can be liquidated
+        let health_factor = math::health_factor(
+            collateral_account.lamport_balance,
+            collateral_account.amount_minted,
+            config_account.liquidation_threshold,
+            price,
+        )?;
+        require!(
+            health_factor < config_account.min_health_factor,
+            StablecointError::AboveMinimumHealthFactor
+        );
+
+        let outcome = math::liquidate_position(
+            collateral_account.lamport_balance,
+            collateral_account.amount_minted,
+            amount_to_burn,
+            config_account.liquidation_bonus,
+            price,
+        )?;
+
+        let lamports_out =
+            vault_withdrawal(&sol_account, outcome.lamports_paid, outcome.lamport_balance)?;
+        withdraw_sol(
+            &sol_account,
+            &context.accounts.liquidator,
+            &context.accounts.system_program,
+            &collateral_account.creator,
+            collateral_account.position_id,
+            collateral_account.bump_sol_account,
+            lamports_out,
+        )?;
+
+        collateral_account.lamport_balance = outcome.lamport_balance;
+        collateral_account.amount_minted = outcome.amount_minted;
+
+        let health_factor = math::health_factor(
+            collateral_account.lamport_balance,
+            collateral_account.amount_minted,
+            config_account.liquidation_threshold,
+            price,
+        )?;
+        require!(
+            health_factor >= config_account.min_health_factor,
+            StablecointError::BelowMinimumHealthFactor
+        );
+
+        // written back right away, so a position listed twice is read fresh the second time
+        collateral_account.exit(&crate::ID)?;
+        total_to_burn = total_to_burn
+            .checked_add(amount_to_burn)
+            .ok_or(StablecointError::MathError)?;
+    }
+
+    burn_tokens(
+        &context.accounts.token_program,
+        &context.accounts.mint_account,
+        &context.accounts.token_account,
+        &context.accounts.liquidator,
+        total_to_burn,
+    )?;
+
+    Ok(())
+}
+
+// the checks `Liquidate` gets from its account constraints
+fn load_collateral<'info>(
+    account: &'info AccountInfo<'info>,
+) -> Result<Account<'info, Collateral>> {
+    require!(account.is_writable, ErrorCode::ConstraintMut);
+    let collateral_account: Account<Collateral> = Account::try_from(account)?;
+    let expected = Pubkey::create_program_address(
+        &[
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+            &[collateral_account.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::ConstraintSeeds)?;
+    require_keys_eq!(account.key(), expected, ErrorCode::ConstraintSeeds);
+    Ok(collateral_account)
+}
+
+fn load_sol_account<'info>(
+    account: &'info AccountInfo<'info>,
+    collateral_account: &Collateral,
+) -> Result<SystemAccount<'info>> {
+    require!(account.is_writable, ErrorCode::ConstraintMut);
+    let expected = Pubkey::create_program_address(
+        &[
+            SEED_SOL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+            &[collateral_account.bump_sol_account],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::ConstraintSeeds)?;
+    require_keys_eq!(account.key(), expected, ErrorCode::ConstraintSeeds);
+    require_keys_eq!(
+        account.key(),
+        collateral_account.sol_account,
+        ErrorCode::ConstraintHasOne
+    );
+    SystemAccount::try_from(account)
+}
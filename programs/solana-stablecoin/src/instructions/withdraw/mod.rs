@@ -4,3 +4,5 @@ pub mod utils;
 pub use utils::*;
 pub mod liquidate;
 pub use liquidate::*;
+pub mod liquidate_batch;
+pub use liquidate_batch::*;
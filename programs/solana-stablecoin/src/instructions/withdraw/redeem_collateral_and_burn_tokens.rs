@@ -1,11 +1,14 @@
 use crate::{
     burn_tokens, check_health_factor,
     error::StablecointError,
+    get_sol_usd_price, is_position_holder,
+    math::{self, AmountMode},
     state::{Collateral, Config},
-    withdraw_sol, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
+    vault_withdrawal, withdraw_sol, SEED_AUCTION, SEED_BLOCKLIST, SEED_COLLATERAL_ACCOUNT,
+    SEED_CONFIG_ACCOUNT,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 #[derive(Accounts)]
 pub struct RedeemCollateralAndBurnTokens<'info> {
@@ -22,13 +25,22 @@ pub struct RedeemCollateralAndBurnTokens<'info> {
 
     #[account(
         mut,
-        seeds = [SEED_COLLATERAL_ACCOUNT, depositor.key().as_ref()],
+        seeds = [
+            SEED_COLLATERAL_ACCOUNT,
+            collateral_account.creator.as_ref(),
+            &collateral_account.position_id.to_le_bytes(),
+        ],
         bump = collateral_account.bump,
         has_one = sol_account,
-        has_one = token_account
     )]
     pub collateral_account: Account<'info, Collateral>,
 
+    #[account(
+        constraint = is_position_holder(&collateral_account, &position_token_account, &depositor.key())
+            @ StablecointError::NotPositionHolder
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
     #[account(mut)]
     pub sol_account: SystemAccount<'info>,
     #[account(mut)]
@@ -36,25 +48,58 @@ pub struct RedeemCollateralAndBurnTokens<'info> {
     #[account(mut)]
     pub token_account: InterfaceAccount<'info, TokenAccount>,
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token2022>,
+    #[account(address = config_account.token_program @ StablecointError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: only holds data when the address is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, depositor.key().as_ref()],
+        bump,
+        constraint = depositor_blocklist_entry.data_is_empty() @ StablecointError::Blocklisted
+    )]
+    pub depositor_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only holds data while an auction sells the position's seized collateral
+    #[account(
+        seeds = [SEED_AUCTION, collateral_account.key().as_ref()],
+        bump,
+        constraint = auction.data_is_empty() @ StablecointError::AuctionInProgress
+    )]
+    pub auction: UncheckedAccount<'info>,
 }
 
+/// burn stablecoin and withdraw SOL from the position
+/// # Arguments
+/// * `amount_collateral` - Lamports to withdraw, `MaxSafe` is sized at the current price after the burn
+/// * `amount_to_burn` - Stablecoin to burn
 pub fn process_redeem_collateral_and_burn_tokens(
     context: Context<RedeemCollateralAndBurnTokens>,
-    amount_collateral: u64,
+    amount_collateral: AmountMode,
     amount_to_burn: u64,
 ) -> Result<()> {
     let collateral_account = &mut context.accounts.collateral_account;
-    collateral_account.lamport_balance = context
-        .accounts
-        .sol_account
-        .lamports()
-        .checked_sub(amount_collateral)
-        .ok_or(StablecointError::MathError)?;
+    collateral_account.depositor = context.accounts.depositor.key();
     collateral_account.amount_minted = collateral_account
         .amount_minted
         .checked_sub(amount_to_burn)
         .ok_or(StablecointError::MathError)?;
+    let amount_collateral = math::withdraw_amount(
+        amount_collateral,
+        collateral_account.lamport_balance,
+        collateral_account.amount_minted,
+        context.accounts.config_account.liquidation_threshold,
+        context.accounts.config_account.min_health_factor,
+        get_sol_usd_price(&context.accounts.price_update)?,
+    )?;
+    collateral_account.lamport_balance = collateral_account
+        .lamport_balance
+        .checked_sub(amount_collateral)
+        .ok_or(StablecointError::MathError)?;
+    let lamports_out = vault_withdrawal(
+        &context.accounts.sol_account,
+        amount_collateral,
+        collateral_account.lamport_balance,
+    )?;
 
     check_health_factor(
         &context.accounts.collateral_account,
@@ -74,9 +119,10 @@ pub fn process_redeem_collateral_and_burn_tokens(
         &context.accounts.sol_account,
         &context.accounts.depositor.to_account_info(),
         &context.accounts.system_program,
-        &context.accounts.depositor.key(),
+        &context.accounts.collateral_account.creator,
+        context.accounts.collateral_account.position_id,
         context.accounts.collateral_account.bump_sol_account,
-        amount_collateral,
+        lamports_out,
     )?;
 
     Ok(())
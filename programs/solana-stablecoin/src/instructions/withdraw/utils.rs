@@ -1,23 +1,49 @@
-use crate::{helpers::transfer_sol_from_pda, SEED_SOL_ACCOUNT};
+use crate::{error::StablecointError, helpers::transfer_sol_from_pda, SEED_SOL_ACCOUNT};
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{burn, Burn};
-use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
 
 pub fn withdraw_sol<'info>(
     from: &SystemAccount<'info>,
     to: &AccountInfo<'info>,
     system_program: &Program<'info, System>,
-    depositor_key: &Pubkey,
+    creator_key: &Pubkey,
+    position_id: u64,
     bump: u8,
     amount: u64,
 ) -> Result<()> {
-    let signer_seeds: &[&[&[u8]]] = &[&[SEED_SOL_ACCOUNT, depositor_key.as_ref(), &[bump]]];
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_SOL_ACCOUNT,
+        creator_key.as_ref(),
+        &position_id.to_le_bytes(),
+        &[bump],
+    ]];
     transfer_sol_from_pda(from, to, amount, system_program, signer_seeds)?;
     Ok(())
 }
 
+/// Lamports to take out of the vault when `amount` of collateral leaves it and the
+/// position keeps `lamport_balance`.
+///
+/// Vaults funded before the rent reserve hold no more than their collateral and stray
+/// transfers, so once the collateral is gone a remainder below the rent-exempt minimum
+/// would fail the transaction. It leaves with the withdrawal instead.
+pub fn vault_withdrawal(
+    sol_account: &SystemAccount,
+    amount: u64,
+    lamport_balance: u64,
+) -> Result<u64> {
+    let remaining = sol_account
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(StablecointError::MathError)?;
+    if lamport_balance == 0 && remaining < Rent::get()?.minimum_balance(0) {
+        return Ok(sol_account.lamports());
+    }
+    Ok(amount)
+}
+
 pub fn burn_tokens<'info>(
-    token_program: &Program<'info, Token2022>,
+    token_program: &Interface<'info, TokenInterface>,
     mint_account: &InterfaceAccount<'info, Mint>,
     token_account: &InterfaceAccount<'info, TokenAccount>,
     authority: &Signer<'info>,
@@ -1,12 +1,16 @@
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod math;
+pub mod pda;
 pub mod state;
 
 use anchor_lang::prelude::*;
 
 pub use constants::*;
 pub use instructions::*;
+pub use math::{AmountMode, AuctionCurve};
 pub use state::*;
 
 declare_id!("6B2Hxx7Lv6ohAtSKEoEE5T2rrf9XzKA8mGddiAknpeJA");
@@ -15,31 +19,243 @@ declare_id!("6B2Hxx7Lv6ohAtSKEoEE5T2rrf9XzKA8mGddiAknpeJA");
 pub mod solana_stablecoin {
     use super::*;
 
-    pub fn initialize_config(context: Context<InitializeConfig>) -> Result<()> {
-        process_initialize_config(context)
+    pub fn initialize_config(
+        context: Context<InitializeConfig>,
+        name: String,
+        symbol: String,
+        uri: String,
+        recovery_authority: Pubkey,
+    ) -> Result<()> {
+        process_initialize_config(context, name, symbol, uri, recovery_authority)
+    }
+
+    pub fn initialize_config_legacy(
+        context: Context<InitializeConfigLegacy>,
+        recovery_authority: Pubkey,
+    ) -> Result<()> {
+        process_initialize_config_legacy(context, recovery_authority)
     }
 
     pub fn update_config(context: Context<UpdateConfig>, min_health_factor: u64) -> Result<()> {
         process_update_config(context, min_health_factor)
     }
 
+    pub fn set_compliance_authority(
+        context: Context<SetComplianceAuthority>,
+        compliance_authority: Pubkey,
+    ) -> Result<()> {
+        process_set_compliance_authority(context, compliance_authority)
+    }
+
+    pub fn set_recovery_authority(
+        context: Context<SetRecoveryAuthority>,
+        recovery_authority: Pubkey,
+        recovery_timelock: i64,
+    ) -> Result<()> {
+        process_set_recovery_authority(context, recovery_authority, recovery_timelock)
+    }
+
+    pub fn set_auction_parameters(
+        context: Context<SetAuctionParameters>,
+        premium_bps: u64,
+        floor_bps: u64,
+        curve: AuctionCurve,
+    ) -> Result<()> {
+        process_set_auction_parameters(context, premium_bps, floor_bps, curve)
+    }
+
+    pub fn set_keeper_tip(context: Context<SetKeeperTip>, keeper_tip: u64) -> Result<()> {
+        process_set_keeper_tip(context, keeper_tip)
+    }
+
+    pub fn sweep_donations(context: Context<SweepDonations>) -> Result<()> {
+        process_sweep_donations(context)
+    }
+
+    pub fn add_to_blocklist(context: Context<AddToBlocklist>, address: Pubkey) -> Result<()> {
+        process_add_to_blocklist(context, address)
+    }
+
+    pub fn remove_from_blocklist(context: Context<RemoveFromBlocklist>) -> Result<()> {
+        process_remove_from_blocklist(context)
+    }
+
+    pub fn freeze_account(context: Context<FreezeTokenAccount>) -> Result<()> {
+        process_freeze_token_account(context)
+    }
+
+    pub fn thaw_account(context: Context<ThawTokenAccount>) -> Result<()> {
+        process_thaw_token_account(context)
+    }
+
+    pub fn update_token_metadata(
+        context: Context<UpdateTokenMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        process_update_token_metadata(context, name, symbol, uri)
+    }
+
+    pub fn open_position(context: Context<OpenPosition>, position_id: u64) -> Result<()> {
+        process_open_position(context, position_id)
+    }
+
     pub fn deposit_collateral_and_mint_tokens(
         context: Context<DepositCollateralAndMintTokens>,
         amount_collateral: u64,
-        amount_to_mint: u64,
+        amount_to_mint: AmountMode,
     ) -> Result<()> {
         process_deposit_collateral_and_mint_tokens(context, amount_collateral, amount_to_mint)
     }
 
     pub fn redeem_collateral_and_burn_tokens(
         context: Context<RedeemCollateralAndBurnTokens>,
-        amount_collateral: u64,
+        amount_collateral: AmountMode,
         amount_to_burn: u64,
     ) -> Result<()> {
         process_redeem_collateral_and_burn_tokens(context, amount_collateral, amount_to_burn)
     }
 
+    pub fn close_position(context: Context<ClosePosition>) -> Result<()> {
+        process_close_position(context)
+    }
+
+    pub fn propose_position_transfer(
+        context: Context<ProposePositionTransfer>,
+        new_depositor: Pubkey,
+    ) -> Result<()> {
+        process_propose_position_transfer(context, new_depositor)
+    }
+
+    pub fn accept_position_transfer(context: Context<AcceptPositionTransfer>) -> Result<()> {
+        process_accept_position_transfer(context)
+    }
+
+    pub fn approve_operator(
+        context: Context<ApproveOperator>,
+        permissions: u8,
+        mint_cap: u64,
+    ) -> Result<()> {
+        process_approve_operator(context, permissions, mint_cap)
+    }
+
+    pub fn revoke_operator(context: Context<RevokeOperator>) -> Result<()> {
+        process_revoke_operator(context)
+    }
+
+    pub fn operator_deposit_collateral_and_mint_tokens(
+        context: Context<OperatorDepositCollateralAndMintTokens>,
+        amount_collateral: u64,
+        amount_to_mint: u64,
+    ) -> Result<()> {
+        process_operator_deposit_collateral_and_mint_tokens(
+            context,
+            amount_collateral,
+            amount_to_mint,
+        )
+    }
+
+    pub fn operator_redeem_collateral_and_burn_tokens(
+        context: Context<OperatorRedeemCollateralAndBurnTokens>,
+        amount_collateral: u64,
+        amount_to_burn: u64,
+    ) -> Result<()> {
+        process_operator_redeem_collateral_and_burn_tokens(
+            context,
+            amount_collateral,
+            amount_to_burn,
+        )
+    }
+
     pub fn liquidate(context: Context<Liquidate>, amount_to_burn: u64) -> Result<()> {
         process_liquidate(context, amount_to_burn)
     }
+
+    pub fn liquidate_batch<'info>(
+        context: Context<'_, '_, 'info, 'info, LiquidateBatch<'info>>,
+        amounts_to_burn: Vec<u64>,
+    ) -> Result<()> {
+        process_liquidate_batch(context, amounts_to_burn)
+    }
+
+    pub fn start_liquidation_auction(context: Context<StartLiquidationAuction>) -> Result<()> {
+        process_start_liquidation_auction(context)
+    }
+
+    pub fn bid(context: Context<Bid>, amount_to_burn: u64) -> Result<()> {
+        process_bid(context, amount_to_burn)
+    }
+
+    pub fn get_position_health(context: Context<ViewPosition>) -> Result<PositionHealth> {
+        process_get_position_health(context)
+    }
+
+    pub fn get_max_mintable(context: Context<ViewPosition>) -> Result<u64> {
+        process_get_max_mintable(context)
+    }
+
+    pub fn get_max_withdrawable(context: Context<ViewPosition>) -> Result<u64> {
+        process_get_max_withdrawable(context)
+    }
+
+    pub fn get_liquidation_price(context: Context<ViewPosition>) -> Result<LiquidationPrice> {
+        process_get_liquidation_price(context)
+    }
+
+    pub fn initialize_savings_vault(
+        context: Context<InitializeSavingsVault>,
+        savings_rate_bps: u64,
+        mint_budget: u64,
+    ) -> Result<()> {
+        process_initialize_savings_vault(context, savings_rate_bps, mint_budget)
+    }
+
+    pub fn update_savings_vault(
+        context: Context<UpdateSavingsVault>,
+        savings_rate_bps: u64,
+        mint_budget: u64,
+    ) -> Result<()> {
+        process_update_savings_vault(context, savings_rate_bps, mint_budget)
+    }
+
+    pub fn deposit_savings<'info>(
+        context: Context<'_, '_, '_, 'info, DepositSavings<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        process_deposit_savings(context, amount)
+    }
+
+    pub fn withdraw_savings<'info>(
+        context: Context<'_, '_, '_, 'info, WithdrawSavings<'info>>,
+        shares: u64,
+    ) -> Result<()> {
+        process_withdraw_savings(context, shares)
+    }
+
+    pub fn drip_savings(context: Context<DripSavings>) -> Result<()> {
+        process_drip_savings(context)
+    }
+
+    pub fn schedule_recovery(
+        context: Context<ScheduleRecovery>,
+        amount: u64,
+        destination: Option<Pubkey>,
+    ) -> Result<()> {
+        process_schedule_recovery(context, amount, destination)
+    }
+
+    pub fn cancel_recovery(context: Context<CancelRecovery>) -> Result<()> {
+        process_cancel_recovery(context)
+    }
+
+    pub fn force_burn(context: Context<ForceBurn>) -> Result<()> {
+        process_force_burn(context)
+    }
+
+    pub fn force_transfer<'info>(
+        context: Context<'_, '_, '_, 'info, ForceTransfer<'info>>,
+    ) -> Result<()> {
+        process_force_transfer(context)
+    }
 }
@@ -0,0 +1,548 @@
+//! Risk math over plain numbers: valuation, health factor and liquidation payouts.
+//!
+//! Lamports and the stablecoin both have 9 decimals, so a USD amount in stablecoin base
+//! units is `lamports * price * 10^exponent`. The instructions, the client SDK and the
+//! keeper all call these functions, the instructions only read the inputs from accounts.
+//!
+//! Every division states its rounding direction. Results always round against the
+//! user: collateral is valued down, the health factor rounds down and liquidation
+//! payouts round down, so rounding never lets anyone take out more than they put in.
+use crate::{error::StablecointError, BASIS_POINTS};
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::Price;
+
+// 10^18 times a u64 amount still fits in a u128
+const MAX_EXPONENT: i32 = 18;
+
+/// SOL/USD price as published by Pyth, `price * 10^exponent` dollars per SOL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub exponent: i32,
+}
+
+impl OraclePrice {
+    pub fn new(price: i64, exponent: i32) -> Self {
+        Self { price, exponent }
+    }
+
+    /// `price` and `10^|exponent|`, rejecting prices that are zero, negative or use an
+    /// exponent too large to scale amounts by.
+    fn parts(&self) -> Result<(u128, u128)> {
+        require!(
+            self.price > 0 && self.exponent.abs() <= MAX_EXPONENT,
+            StablecointError::InvalidPrice
+        );
+        Ok((self.price as u128, 10u128.pow(self.exponent.unsigned_abs())))
+    }
+}
+
+impl From<Price> for OraclePrice {
+    fn from(price: Price) -> Self {
+        Self::new(price.price, price.exponent)
+    }
+}
+
+/// How an instruction picks an amount, resolved on-chain against the current price.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmountMode {
+    Exact { amount: u64 },
+    // the maximum that keeps the minimum health factor, less `buffer_bps` of headroom
+    MaxSafe { buffer_bps: u16 },
+    // the maximum for minting, the whole balance for withdrawals
+    All,
+}
+
+impl From<u64> for AmountMode {
+    fn from(amount: u64) -> Self {
+        Self::Exact { amount }
+    }
+}
+
+/// How an auction's price falls from its start price to its floor.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuctionCurve {
+    // straight line from the start price to the floor over `duration` seconds
+    Linear { duration: i64 },
+    // `cut_bps` off the price every `step` seconds until it reaches the floor
+    Exponential { step: i64, cut_bps: u64 },
+}
+
+/// A bid's slice of an auction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuctionFill {
+    pub amount_burned: u64,
+    pub lamports_paid: u64,
+}
+
+/// Result of a liquidation on the position's balances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiquidationOutcome {
+    pub lamports_paid: u64, // collateral sent to the liquidator, bonus included
+    pub lamport_balance: u64,
+    pub amount_minted: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+/// `a * b / c` in u128, failing instead of truncating when the result exceeds a u64.
+pub fn mul_div(a: u128, b: u128, c: u128, rounding: Rounding) -> Result<u64> {
+    require!(c > 0, StablecointError::MathError);
+    let product = a.checked_mul(b).ok_or(StablecointError::MathError)?;
+    let mut quotient = product / c;
+    if rounding == Rounding::Up && product % c != 0 {
+        quotient += 1;
+    }
+    Ok(u64::try_from(quotient).map_err(|_| StablecointError::MathError)?)
+}
+
+/// USD value of `amount_in_lamports`.
+///
+/// Rounds down: collateral is never valued above what it is worth.
+pub fn usd_value(amount_in_lamports: u64, price: OraclePrice) -> Result<u64> {
+    usd_for_lamports(amount_in_lamports, price, Rounding::Down)
+}
+
+fn usd_for_lamports(
+    amount_in_lamports: u64,
+    price: OraclePrice,
+    rounding: Rounding,
+) -> Result<u64> {
+    let negative_exponent = price.exponent < 0;
+    let (price, scale) = price.parts()?;
+    let amount = amount_in_lamports as u128;
+    if negative_exponent {
+        mul_div(amount, price, scale, rounding)
+    } else {
+        mul_div(amount, price * scale, 1, rounding)
+    }
+}
+
+/// Lamports worth `amount_in_usd`.
+///
+/// Rounds down: a liquidator burning `amount_in_usd` receives at most its value.
+pub fn lamports_from_usd(amount_in_usd: u64, price: OraclePrice) -> Result<u64> {
+    lamports_for_usd(amount_in_usd, price, Rounding::Down)
+}
+
+fn lamports_for_usd(amount_in_usd: u64, price: OraclePrice, rounding: Rounding) -> Result<u64> {
+    let negative_exponent = price.exponent < 0;
+    let (price, scale) = price.parts()?;
+    let amount = amount_in_usd as u128;
+    if negative_exponent {
+        mul_div(amount, scale, price, rounding)
+    } else {
+        mul_div(amount, 1, price * scale, rounding)
+    }
+}
+
+/// Smallest collateral value whose threshold-adjusted value covers `amount_minted` at
+/// `min_health_factor`, `None` when no value is enough.
+fn required_collateral_value(
+    amount_minted: u64,
+    liquidation_threshold: u64,
+    min_health_factor: u64,
+) -> Result<Option<u64>> {
+    let required_adjusted = (amount_minted as u128)
+        .checked_mul(min_health_factor as u128)
+        .ok_or(StablecointError::MathError)?;
+    if required_adjusted == 0 {
+        return Ok(Some(0));
+    }
+    if liquidation_threshold == 0 {
+        return Ok(None);
+    }
+    // floor(value * threshold / 100) >= required exactly when value >= ceil(required * 100 / threshold)
+    match mul_div(
+        required_adjusted,
+        100,
+        liquidation_threshold as u128,
+        Rounding::Up,
+    ) {
+        Ok(value) => Ok(Some(value)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Collateral value adjusted for `liquidation_threshold` percent, divided by the debt.
+/// `u64::MAX` when nothing has been minted.
+///
+/// Rounds down at every step: a position is never reported healthier than it is.
+pub fn health_factor(
+    lamport_balance: u64,
+    amount_minted: u64,
+    liquidation_threshold: u64,
+    price: OraclePrice,
+) -> Result<u64> {
+    let collateral_value_in_usd = usd_value(lamport_balance, price)?;
+    if amount_minted == 0 {
+        return Ok(u64::MAX);
+    }
+    // we are dividing by 100 because it is %
+    let collateral_adjusted_for_liquidation_threshold = mul_div(
+        collateral_value_in_usd as u128,
+        liquidation_threshold as u128,
+        100,
+        Rounding::Down,
+    )?;
+    mul_div(
+        collateral_adjusted_for_liquidation_threshold as u128,
+        1,
+        amount_minted as u128,
+        Rounding::Down,
+    )
+}
+
+/// Whether `liquidate` accepts a position with this health factor.
+pub fn is_liquidatable(health_factor: u64, min_health_factor: u64) -> bool {
+    health_factor < min_health_factor
+}
+
+/// Balances after burning `amount_to_burn` of the debt, paying its value in lamports
+/// plus `liquidation_bonus` percent. Fails when the position can't cover the payout.
+///
+/// Rounds down: both the burned value and the bonus favour the position.
+pub fn liquidate_position(
+    lamport_balance: u64,
+    amount_minted: u64,
+    amount_to_burn: u64,
+    liquidation_bonus: u64,
+    price: OraclePrice,
+) -> Result<LiquidationOutcome> {
+    let lamports = lamports_from_usd(amount_to_burn, price)?;
+    let bonus = mul_div(
+        lamports as u128,
+        liquidation_bonus as u128,
+        100,
+        Rounding::Down,
+    )?;
+    let lamports_paid = lamports
+        .checked_add(bonus)
+        .ok_or(StablecointError::MathError)?;
+    require!(
+        lamports_paid <= lamport_balance,
+        StablecointError::InsufficientCollateral
+    );
+    Ok(LiquidationOutcome {
+        lamports_paid,
+        lamport_balance: lamport_balance - lamports_paid,
+        amount_minted: amount_minted
+            .checked_sub(amount_to_burn)
+            .ok_or(StablecointError::MathError)?,
+    })
+}
+
+/// Largest debt `lamport_balance` can back at `min_health_factor`, `u64::MAX` when
+/// the minimum is 0.
+///
+/// Rounds down: minting up to this keeps the health factor at or above the minimum.
+pub fn max_debt(
+    lamport_balance: u64,
+    liquidation_threshold: u64,
+    min_health_factor: u64,
+    price: OraclePrice,
+) -> Result<u64> {
+    let collateral_value_in_usd = usd_value(lamport_balance, price)?;
+    if min_health_factor == 0 {
+        return Ok(u64::MAX);
+    }
+    let collateral_adjusted_for_liquidation_threshold = mul_div(
+        collateral_value_in_usd as u128,
+        liquidation_threshold as u128,
+        100,
+        Rounding::Down,
+    )?;
+    mul_div(
+        collateral_adjusted_for_liquidation_threshold as u128,
+        1,
+        min_health_factor as u128,
+        Rounding::Down,
+    )
+}
+
+/// Lamports that can be withdrawn while keeping the health factor at or above
+/// `min_health_factor`.
+///
+/// Rounds down: the collateral that has to stay is rounded up.
+pub fn max_withdrawable(
+    lamport_balance: u64,
+    amount_minted: u64,
+    liquidation_threshold: u64,
+    min_health_factor: u64,
+    price: OraclePrice,
+) -> Result<u64> {
+    let required_lamports = required_lamports(
+        amount_minted,
+        liquidation_threshold,
+        min_health_factor,
+        price,
+    )?;
+    Ok(lamport_balance.saturating_sub(required_lamports))
+}
+
+/// Lamports a position needs to back `amount_minted` at `min_health_factor`, rounded
+/// up, `u64::MAX` when no u64 of lamports is enough.
+fn required_lamports(
+    amount_minted: u64,
+    liquidation_threshold: u64,
+    min_health_factor: u64,
+    price: OraclePrice,
+) -> Result<u64> {
+    price.parts()?;
+    match required_collateral_value(amount_minted, liquidation_threshold, min_health_factor)? {
+        Some(value) => Ok(lamports_for_usd(value, price, Rounding::Up).unwrap_or(u64::MAX)),
+        None => Ok(u64::MAX),
+    }
+}
+
+/// Lowest price, with `exponent`, at which the position is not liquidatable. Below it
+/// the position can be liquidated. 0 when it can't become liquidatable at any price,
+/// `i64::MAX` when it is liquidatable at every price.
+///
+/// Rounds up: the position is never reported safe at a price where it isn't.
+pub fn liquidation_price(
+    lamport_balance: u64,
+    amount_minted: u64,
+    liquidation_threshold: u64,
+    min_health_factor: u64,
+    exponent: i32,
+) -> Result<i64> {
+    require!(
+        exponent.abs() <= MAX_EXPONENT,
+        StablecointError::InvalidPrice
+    );
+    let Some(required_value) =
+        required_collateral_value(amount_minted, liquidation_threshold, min_health_factor)?
+    else {
+        return Ok(i64::MAX);
+    };
+    if required_value == 0 {
+        return Ok(0);
+    }
+    if lamport_balance == 0 {
+        return Ok(i64::MAX);
+    }
+    // lamports * price * 10^exponent >= required value
+    let scale = 10u128.pow(exponent.unsigned_abs());
+    let price = if exponent < 0 {
+        mul_div(
+            required_value as u128,
+            scale,
+            lamport_balance as u128,
+            Rounding::Up,
+        )
+    } else {
+        mul_div(
+            required_value as u128,
+            1,
+            lamport_balance as u128 * scale,
+            Rounding::Up,
+        )
+    };
+    Ok(price.map_or(i64::MAX, |price| i64::try_from(price).unwrap_or(i64::MAX)))
+}
+
+/// Stablecoin to mint for `mode` on a position holding `lamport_balance` (deposit
+/// included) with `amount_minted` of debt.
+///
+/// `MaxSafe` keeps the debt at or below the maximum minus `buffer_bps` of it, rounded
+/// down. `All` is `MaxSafe` without a buffer.
+pub fn mint_amount(
+    mode: AmountMode,
+    lamport_balance: u64,
+    amount_minted: u64,
+    liquidation_threshold: u64,
+    min_health_factor: u64,
+    price: OraclePrice,
+) -> Result<u64> {
+    let buffer_bps = match mode {
+        AmountMode::Exact { amount } => return Ok(amount),
+        AmountMode::MaxSafe { buffer_bps } => buffer_bps,
+        AmountMode::All => 0,
+    };
+    let max_debt = max_debt(
+        lamport_balance,
+        liquidation_threshold,
+        min_health_factor,
+        price,
+    )?;
+    let safe_debt = mul_div(
+        max_debt as u128,
+        remaining_bps(buffer_bps)?,
+        BASIS_POINTS as u128,
+        Rounding::Down,
+    )?;
+    Ok(safe_debt.saturating_sub(amount_minted))
+}
+
+/// Lamports to withdraw for `mode` from a position holding `lamport_balance` with
+/// `amount_minted` of debt (burn included).
+///
+/// `MaxSafe` leaves the required collateral plus `buffer_bps` of it, rounded up.
+/// `All` withdraws everything, which only passes the health check without debt.
+pub fn withdraw_amount(
+    mode: AmountMode,
+    lamport_balance: u64,
+    amount_minted: u64,
+    liquidation_threshold: u64,
+    min_health_factor: u64,
+    price: OraclePrice,
+) -> Result<u64> {
+    let buffer_bps = match mode {
+        AmountMode::Exact { amount } => return Ok(amount),
+        AmountMode::MaxSafe { buffer_bps } => buffer_bps,
+        AmountMode::All => return Ok(lamport_balance),
+    };
+    remaining_bps(buffer_bps)?;
+    let required_lamports = required_lamports(
+        amount_minted,
+        liquidation_threshold,
+        min_health_factor,
+        price,
+    )?;
+    let safe_lamports = mul_div(
+        required_lamports as u128,
+        (BASIS_POINTS + buffer_bps as u64) as u128,
+        BASIS_POINTS as u128,
+        Rounding::Up,
+    )
+    .unwrap_or(u64::MAX);
+    Ok(lamport_balance.saturating_sub(safe_lamports))
+}
+
+fn remaining_bps(buffer_bps: u16) -> Result<u128> {
+    require!(
+        buffer_bps as u64 <= BASIS_POINTS,
+        StablecointError::InvalidAmountBuffer
+    );
+    Ok((BASIS_POINTS - buffer_bps as u64) as u128)
+}
+
+// fixed point scale for compounding exponential auction cuts
+const AUCTION_FACTOR_SCALE: u128 = 1_000_000_000_000;
+
+/// Checks an auction configuration: the floor is a positive fraction of the oracle price
+/// no higher than the start price and the curve actually moves.
+pub fn validate_auction_parameters(
+    premium_bps: u64,
+    floor_bps: u64,
+    curve: AuctionCurve,
+) -> Result<()> {
+    let start_bps = BASIS_POINTS
+        .checked_add(premium_bps)
+        .ok_or(StablecointError::MathError)?;
+    let valid_curve = match curve {
+        AuctionCurve::Linear { duration } => duration > 0,
+        AuctionCurve::Exponential { step, cut_bps } => {
+            step > 0 && cut_bps > 0 && cut_bps <= BASIS_POINTS
+        }
+    };
+    require!(
+        floor_bps > 0 && floor_bps <= start_bps && valid_curve,
+        StablecointError::InvalidAuctionParameters
+    );
+    Ok(())
+}
+
+/// `price` scaled by `bps` basis points.
+///
+/// Rounds up: auction prices are what a bidder pays per SOL, so they favour the position.
+pub fn scale_price(price: OraclePrice, bps: u64) -> Result<OraclePrice> {
+    let (value, _) = price.parts()?;
+    let scaled = mul_div(value, bps as u128, BASIS_POINTS as u128, Rounding::Up)?;
+    Ok(OraclePrice::new(
+        i64::try_from(scaled).map_err(|_| StablecointError::MathError)?,
+        price.exponent,
+    ))
+}
+
+/// Price `elapsed` seconds into an auction that started at `start_price` and never goes
+/// below `floor_price`, both in the same exponent.
+///
+/// Rounds up: the price falls no faster than the curve.
+pub fn auction_price(
+    start_price: i64,
+    floor_price: i64,
+    elapsed: i64,
+    curve: AuctionCurve,
+) -> Result<i64> {
+    require!(
+        floor_price > 0 && floor_price <= start_price,
+        StablecointError::InvalidPrice
+    );
+    let elapsed = elapsed.max(0) as u128;
+    let price = match curve {
+        AuctionCurve::Linear { duration } => {
+            let duration = duration.max(1) as u128;
+            if elapsed >= duration {
+                return Ok(floor_price);
+            }
+            let decayed = mul_div(
+                (start_price - floor_price) as u128,
+                elapsed,
+                duration,
+                Rounding::Down,
+            )?;
+            start_price as u128 - decayed as u128
+        }
+        AuctionCurve::Exponential { step, cut_bps } => {
+            let steps = elapsed / step.max(1) as u128;
+            let factor = compound_cut(cut_bps, steps)?;
+            mul_div(
+                start_price as u128,
+                factor,
+                AUCTION_FACTOR_SCALE,
+                Rounding::Up,
+            )? as u128
+        }
+    };
+    Ok((price as i64).max(floor_price))
+}
+
+/// `(1 - cut_bps / 10000)^steps` scaled by `AUCTION_FACTOR_SCALE`, each product
+/// rounded up.
+fn compound_cut(cut_bps: u64, mut steps: u128) -> Result<u128> {
+    let remaining = BASIS_POINTS.saturating_sub(cut_bps) as u128;
+    let mut base = remaining * AUCTION_FACTOR_SCALE / BASIS_POINTS as u128;
+    let mut factor = AUCTION_FACTOR_SCALE;
+    // square and multiply, a long auction takes a few dozen products instead of one per step
+    while steps > 0 && factor > 0 {
+        if steps & 1 == 1 {
+            factor = mul_div(factor, base, AUCTION_FACTOR_SCALE, Rounding::Up)? as u128;
+        }
+        base = mul_div(base, base, AUCTION_FACTOR_SCALE, Rounding::Up)? as u128;
+        steps >>= 1;
+    }
+    Ok(factor)
+}
+
+/// What a bid burning up to `amount_to_burn` gets from an auction selling `lamports`
+/// for `debt` at `price`.
+///
+/// Rounds against the bidder: lamports paid round down and, when the bid takes the
+/// last of the collateral, the stablecoin burned for it rounds up.
+pub fn auction_fill(
+    lamports: u64,
+    debt: u64,
+    amount_to_burn: u64,
+    price: OraclePrice,
+) -> Result<AuctionFill> {
+    let amount_burned = amount_to_burn.min(debt);
+    let lamports_paid = lamports_for_usd(amount_burned, price, Rounding::Down)?;
+    if lamports_paid < lamports {
+        return Ok(AuctionFill {
+            amount_burned,
+            lamports_paid,
+        });
+    }
+    // the bid takes everything that's left and only pays what that is worth
+    let value = usd_for_lamports(lamports, price, Rounding::Up)?;
+    Ok(AuctionFill {
+        amount_burned: amount_burned.min(value),
+        lamports_paid: lamports,
+    })
+}
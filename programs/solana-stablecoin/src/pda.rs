@@ -0,0 +1,100 @@
+//! Deterministic addresses for the program accounts.
+//!
+//! A depositor's positions are numbered from 0 up to the `next_position_id` stored in
+//! their `PositionCounter`, so every position they opened can be listed by deriving these
+//! addresses without scanning the program accounts. Position PDAs are seeded with the
+//! creator of the position, so a transferred position keeps its addresses and shows up
+//! under the creator's ids, not the new owner's.
+use crate::{
+    ID, SEED_AUCTION, SEED_BLOCKLIST, SEED_COLLATERAL_ACCOUNT, SEED_CONFIG_ACCOUNT,
+    SEED_DELEGATION, SEED_MINT_ACCOUNT, SEED_POSITION_COUNTER, SEED_POSITION_MINT,
+    SEED_RECOVERY_REQUEST, SEED_SAVINGS_POSITION, SEED_SAVINGS_TOKEN_ACCOUNT, SEED_SAVINGS_VAULT,
+    SEED_SOL_ACCOUNT, SEED_TREASURY,
+};
+use anchor_lang::prelude::*;
+
+pub fn find_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_CONFIG_ACCOUNT], &ID)
+}
+
+pub fn find_mint_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_MINT_ACCOUNT], &ID)
+}
+
+pub fn find_position_counter_address(depositor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_POSITION_COUNTER, depositor.as_ref()], &ID)
+}
+
+pub fn find_collateral_address(creator: &Pubkey, position_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_COLLATERAL_ACCOUNT,
+            creator.as_ref(),
+            &position_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_sol_address(creator: &Pubkey, position_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_SOL_ACCOUNT,
+            creator.as_ref(),
+            &position_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_position_mint_address(collateral_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_POSITION_MINT, collateral_account.as_ref()], &ID)
+}
+
+pub fn find_blocklist_address(address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_BLOCKLIST, address.as_ref()], &ID)
+}
+
+pub fn find_delegation_address(collateral_account: &Pubkey, operator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_DELEGATION,
+            collateral_account.as_ref(),
+            operator.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_savings_vault_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_SAVINGS_VAULT], &ID)
+}
+
+pub fn find_savings_token_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_SAVINGS_TOKEN_ACCOUNT], &ID)
+}
+
+pub fn find_treasury_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_TREASURY], &ID)
+}
+
+pub fn find_savings_position_address(depositor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_SAVINGS_POSITION, depositor.as_ref()], &ID)
+}
+
+pub fn find_recovery_request_address(token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_RECOVERY_REQUEST, token_account.as_ref()], &ID)
+}
+
+pub fn find_auction_address(collateral_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_AUCTION, collateral_account.as_ref()], &ID)
+}
+
+/// Collateral addresses of every position a depositor has opened, given the
+/// `next_position_id` read from their `PositionCounter`.
+pub fn find_collateral_addresses(
+    depositor: &Pubkey,
+    next_position_id: u64,
+) -> impl Iterator<Item = Pubkey> + '_ {
+    (0..next_position_id).map(move |position_id| find_collateral_address(depositor, position_id).0)
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+// an address is blocklisted for as long as its entry exists
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct BlocklistEntry {
+    pub address: Pubkey,
+    pub bump: u8,
+}
@@ -3,12 +3,17 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct Collateral {
-    pub depositor: Pubkey,
+    pub depositor: Pubkey, // last holder of the position NFT to act on the position
+    pub creator: Pubkey,   // depositor that opened the position, the PDA seeds use this key
+    pub position_id: u64,
+    pub position_mint: Pubkey, // whoever holds this NFT manages the position
+    pub pending_depositor: Pubkey, // may take the NFT while a transfer is waiting to be accepted
     pub sol_account: Pubkey,
     pub token_account: Pubkey,
     pub lamport_balance: u64,
     pub amount_minted: u64,
     pub bump: u8,
     pub bump_sol_account: u8,
+    pub bump_position_mint: u8,
     pub is_initialized: bool,
 }
@@ -1,3 +1,4 @@
+use crate::math::AuctionCurve;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -5,10 +6,18 @@ use anchor_lang::prelude::*;
 pub struct Config {
     pub authority: Pubkey,
     pub mint_account: Pubkey,
+    pub compliance_authority: Pubkey, // manages the blocklist and freezes token accounts
+    pub token_program: Pubkey,        // SPL Token or Token-2022, fixed at init
+    pub recovery_authority: Pubkey,   // schedules forced burns and transfers
+    pub recovery_timelock: i64,       // seconds a recovery waits before it can execute
     // the liquidation threshold & bonus should be scaled don't use floats
     pub liquidation_threshold: u64, // this means you can only borrow up to `liquidation_threshold` of your collateral
     pub liquidation_bonus: u64,
     pub min_health_factor: u64,
+    pub auction_premium_bps: u64, // auctions start this far above the oracle price
+    pub auction_floor_bps: u64,   // and stop falling at this fraction of it
+    pub auction_curve: AuctionCurve,
+    pub keeper_tip: u64, // lamports for whoever triggers an auction or a savings accrual
     pub bump: u8,
     pub bump_mint_account: u8,
 }
@@ -0,0 +1,18 @@
+use crate::math::AuctionCurve;
+use anchor_lang::prelude::*;
+
+// collateral seized from an unhealthy position, sold for its debt at a falling price
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct Auction {
+    pub collateral_account: Pubkey,
+    pub starter: Pubkey,  // paid the rent, gets it back when the auction settles
+    pub lamports: u64,    // collateral still for sale, held by this account
+    pub debt: u64,        // stablecoin still to burn
+    pub start_price: i64, // oracle price plus the premium, in `exponent`
+    pub floor_price: i64,
+    pub exponent: i32,
+    pub curve: AuctionCurve, // copied from the config so running auctions keep their terms
+    pub started_at: i64,
+    pub bump: u8,
+}
@@ -2,3 +2,15 @@ pub mod collateral;
 pub use collateral::*;
 pub mod config;
 pub use config::*;
+pub mod savings_vault;
+pub use savings_vault::*;
+pub mod position_counter;
+pub use position_counter::*;
+pub mod operator_delegation;
+pub use operator_delegation::*;
+pub mod blocklist;
+pub use blocklist::*;
+pub mod recovery_request;
+pub use recovery_request::*;
+pub mod liquidation_auction;
+pub use liquidation_auction::*;
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct Delegation {
+    pub collateral_account: Pubkey,
+    pub operator: Pubkey,
+    pub depositor: Pubkey, // owner that approved the operator, the delegation lapses once ownership changes
+    pub permissions: u8,
+    pub mint_cap: u64, // the most debt the operator may have outstanding on the position
+    pub amount_minted: u64, // debt minted by the operator and not yet repaid by them
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
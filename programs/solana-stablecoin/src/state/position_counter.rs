@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct PositionCounter {
+    pub depositor: Pubkey,
+    pub next_position_id: u64, // positions 0..next_position_id have been opened by this depositor
+    pub bump: u8,
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+// a pending forced burn or transfer out of `token_account`, one per account at a time
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct RecoveryRequest {
+    pub token_account: Pubkey,
+    pub destination: Option<Pubkey>, // token account to move the funds to, None burns them
+    pub amount: u64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
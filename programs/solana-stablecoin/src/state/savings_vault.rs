@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct SavingsVault {
+    pub token_account: Pubkey,
+    pub savings_rate_bps: u64, // yearly savings rate in basis points
+    pub mint_budget: u64,      // the most stablecoin the program may ever mint to pay savings
+    pub total_dripped: u64,    // stablecoin minted to the vault so far, never above `mint_budget`
+    pub total_shares: u64,
+    pub exchange_rate: u128, // value of one share, scaled by `SAVINGS_EXCHANGE_RATE_PRECISION`
+    pub last_update_timestamp: i64,
+    pub bump: u8,
+    pub bump_token_account: u8,
+}
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct SavingsPosition {
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::*;
+use solana_sdk::signature::Signer;
+use solana_stablecoin::{error::StablecointError, AmountMode};
+
+#[test]
+fn mint_all_mints_up_to_the_minimum_health_factor() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    // 2 SOL at $100 back $100 with a 50% threshold
+    let position = context
+        .open_and_mint(&user, 0, sol(2), AmountMode::All)
+        .unwrap();
+    assert_eq!(context.collateral(&position).amount_minted, usd(100));
+    assert_eq!(context.stablecoin_balance(&user.pubkey()), usd(100));
+}
+
+#[test]
+fn mint_max_safe_keeps_a_buffer() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context
+        .open_and_mint(&user, 0, sol(2), AmountMode::MaxSafe { buffer_bps: 1_000 })
+        .unwrap();
+    assert_eq!(context.collateral(&position).amount_minted, usd(90));
+
+    // on top of existing debt only the difference is minted, at the current price
+    context.set_price(usd_price(120), START_TIMESTAMP);
+    context
+        .deposit(
+            &user,
+            &position,
+            0,
+            AmountMode::MaxSafe { buffer_bps: 1_000 },
+        )
+        .unwrap();
+    assert_eq!(context.collateral(&position).amount_minted, usd(108));
+}
+
+#[test]
+fn mint_max_safe_counts_the_deposit() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), usd(50)).unwrap();
+    context
+        .deposit(&user, &position, sol(1), AmountMode::All)
+        .unwrap();
+    assert_eq!(context.collateral(&position).amount_minted, usd(100));
+}
+
+#[test]
+fn withdraw_max_safe_leaves_the_required_collateral() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(3), usd(50)).unwrap();
+
+    // $50 needs 1 SOL, plus 10%
+    context
+        .redeem(
+            &user,
+            &position,
+            AmountMode::MaxSafe { buffer_bps: 1_000 },
+            0,
+        )
+        .unwrap();
+    assert_eq!(context.collateral(&position).lamport_balance, sol(11) / 10);
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        sol(11) / 10 + context.vault_rent()
+    );
+
+    // burning first frees more collateral in the same instruction
+    context
+        .redeem(
+            &user,
+            &position,
+            AmountMode::MaxSafe { buffer_bps: 0 },
+            usd(25),
+        )
+        .unwrap();
+    assert_eq!(context.collateral(&position).lamport_balance, sol(1) / 2);
+}
+
+#[test]
+fn withdraw_all_after_repaying() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(2), usd(50)).unwrap();
+
+    let result = context.redeem(&user, &position, AmountMode::All, usd(40));
+    assert_program_error(result, 0, StablecointError::BelowMinimumHealthFactor);
+
+    context
+        .redeem(&user, &position, AmountMode::All, usd(50))
+        .unwrap();
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.lamport_balance, 0);
+    assert_eq!(collateral.amount_minted, 0);
+    // the rent reserve stays until the position is closed
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        context.vault_rent()
+    );
+}
+
+#[test]
+fn buffer_above_basis_points_fails() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let result =
+        context.open_and_mint(&user, 0, sol(2), AmountMode::MaxSafe { buffer_bps: 10_001 });
+    assert_program_error(result.map(|_| ()), 1, StablecointError::InvalidAmountBuffer);
+
+    let position = context.open_and_mint(&user, 0, sol(2), usd(50)).unwrap();
+    let result = context.redeem(
+        &user,
+        &position,
+        AmountMode::MaxSafe { buffer_bps: 10_001 },
+        0,
+    );
+    assert_program_error(result, 0, StablecointError::InvalidAmountBuffer);
+}
@@ -0,0 +1,265 @@
+mod common;
+
+use anchor_lang::Space;
+use common::*;
+use solana_sdk::signature::Signer;
+use solana_stablecoin::{
+    error::StablecointError,
+    math::{self, OraclePrice},
+    state::Auction,
+    AuctionCurve, ANCHOR_DISCRIMINATOR, KEEPER_TIP,
+};
+use solana_stablecoin_client as client;
+
+fn auction_rent(context: &TestContext) -> u64 {
+    context
+        .svm
+        .minimum_balance_for_rent_exemption(ANCHOR_DISCRIMINATOR + Auction::INIT_SPACE)
+}
+
+#[test]
+fn healthy_position_cannot_be_auctioned() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    let result = context.start_auction(&liquidator, &position);
+    assert_program_error(result, 0, StablecointError::AboveMinimumHealthFactor);
+}
+
+#[test]
+fn blocklisted_address_cannot_start_an_auction() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    let authority = context.authority.insecure_clone();
+    context
+        .send(
+            &[client::add_to_blocklist(
+                &authority.pubkey(),
+                liquidator.pubkey(),
+            )],
+            &authority,
+        )
+        .unwrap();
+    let result = context.start_auction(&liquidator, &position);
+    assert_program_error(result, 0, StablecointError::Blocklisted);
+}
+
+#[test]
+fn start_seizes_the_position() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    context.start_auction(&liquidator, &position).unwrap();
+
+    // the starter's tip comes out of the seized collateral
+    let auction = context.auction(&position);
+    assert_eq!(auction.lamports, sol(1) - KEEPER_TIP);
+    assert_eq!(auction.debt, usd(50));
+    // 10% above and half of the $90 oracle price
+    assert_eq!(auction.start_price, usd_price(99));
+    assert_eq!(auction.floor_price, usd_price(45));
+    assert_eq!(auction.starter, liquidator.pubkey());
+    assert_eq!(
+        context.lamports(&position.auction),
+        sol(1) - KEEPER_TIP + auction_rent(&context)
+    );
+
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.lamport_balance, 0);
+    assert_eq!(collateral.amount_minted, 0);
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        context.vault_rent()
+    );
+
+    // one auction per position at a time
+    assert!(context.start_auction(&liquidator, &position).is_err());
+}
+
+#[test]
+fn bids_cover_the_debt_and_return_the_surplus() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    let starter = context.user(sol(1));
+    context.start_auction(&starter, &position).unwrap();
+    let starter_balance = context.lamports(&starter.pubkey());
+    assert_eq!(
+        starter_balance,
+        sol(1) + KEEPER_TIP - auction_rent(&context) - 5_000
+    );
+
+    // $20 at the $99 start price
+    let lamports_before = context.lamports(&liquidator.pubkey());
+    context.bid(&liquidator, &position, usd(20)).unwrap();
+    let first_slice = 202_020_202;
+    assert_eq!(
+        context.lamports(&liquidator.pubkey()),
+        lamports_before + first_slice - 5_000
+    );
+    let auction = context.auction(&position);
+    assert_eq!(auction.debt, usd(30));
+    assert_eq!(auction.lamports, sol(1) - KEEPER_TIP - first_slice);
+
+    // halfway down the linear curve the price is $72, bidding more than the debt
+    // only burns the debt
+    context.warp_to(START_TIMESTAMP + 1_800);
+    context.bid(&liquidator, &position, usd(40)).unwrap();
+    let second_slice = 416_666_666;
+    assert_eq!(context.stablecoin_balance(&liquidator.pubkey()), usd(50));
+
+    let surplus = sol(1) - KEEPER_TIP - first_slice - second_slice;
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.lamport_balance, surplus);
+    assert_eq!(collateral.amount_minted, 0);
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        surplus + context.vault_rent()
+    );
+    // settled, the starter has the rent back
+    assert_eq!(context.lamports(&position.auction), 0);
+    assert_eq!(
+        context.lamports(&starter.pubkey()),
+        starter_balance + auction_rent(&context)
+    );
+}
+
+#[test]
+fn uncovered_debt_is_written_off() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    // without a tip the whole SOL goes under the hammer
+    context.set_keeper_tip(0).unwrap();
+    context.set_price(usd_price(40), START_TIMESTAMP);
+    context.start_auction(&liquidator, &position).unwrap();
+
+    // at the $20 floor 1 SOL only covers $20 of the $50
+    context.warp_to(START_TIMESTAMP + 3_600);
+    context.bid(&liquidator, &position, usd(50)).unwrap();
+    assert_eq!(context.stablecoin_balance(&liquidator.pubkey()), usd(80));
+
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.lamport_balance, 0);
+    assert_eq!(collateral.amount_minted, 0);
+    assert_eq!(context.lamports(&position.auction), 0);
+    // the borrower keeps the $30 nothing backs any more
+    assert_eq!(context.stablecoin_supply(), usd(50) + usd(100) - usd(20));
+}
+
+#[test]
+fn exponential_curve_prices_bids() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    let authority = context.authority.insecure_clone();
+    let curve = AuctionCurve::Exponential {
+        step: 60,
+        cut_bps: 100,
+    };
+    context
+        .send(
+            &[client::set_auction_parameters(
+                &authority.pubkey(),
+                1_000,
+                5_000,
+                curve,
+            )],
+            &authority,
+        )
+        .unwrap();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    context.start_auction(&liquidator, &position).unwrap();
+    assert_eq!(context.auction(&position).curve, curve);
+
+    context.warp_to(START_TIMESTAMP + 600);
+    let price = math::auction_price(usd_price(99), usd_price(45), 600, curve).unwrap();
+    assert!(price < usd_price(90) && price > usd_price(89));
+    let lamports_before = context.lamports(&liquidator.pubkey());
+    context.bid(&liquidator, &position, usd(10)).unwrap();
+    let expected = math::lamports_from_usd(usd(10), OraclePrice::new(price, -8)).unwrap();
+    assert_eq!(
+        context.lamports(&liquidator.pubkey()),
+        lamports_before + expected - 5_000
+    );
+}
+
+#[test]
+fn position_cannot_close_during_an_auction() {
+    let mut context = TestContext::new(100);
+    let borrower = context.user(sol(10));
+    let position = context
+        .open_and_mint(&borrower, 0, sol(1), usd(50))
+        .unwrap();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    context.start_auction(&borrower, &position).unwrap();
+
+    let instruction = client::close_position(&borrower.pubkey(), &position, &TOKEN_PROGRAM);
+    let result = context.send(&[instruction], &borrower);
+    assert_program_error(result, 0, StablecointError::AuctionInProgress);
+}
+
+#[test]
+fn position_cannot_deposit_or_redeem_during_an_auction() {
+    let mut context = TestContext::new(100);
+    let borrower = context.user(sol(10));
+    let position = context
+        .open_and_mint(&borrower, 0, sol(1), usd(50))
+        .unwrap();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    context.start_auction(&borrower, &position).unwrap();
+
+    let result = context.deposit(&borrower, &position, sol(1), 0u64);
+    assert_program_error(result, 0, StablecointError::AuctionInProgress);
+    let result = context.redeem(&borrower, &position, 0u64, 0);
+    assert_program_error(result, 0, StablecointError::AuctionInProgress);
+}
+
+#[test]
+fn auction_parameters_are_validated() {
+    let mut context = TestContext::new(100);
+    let authority = context.authority.insecure_clone();
+    let linear = AuctionCurve::Linear { duration: 600 };
+
+    // the floor can't be above the start price
+    let result = context.send(
+        &[client::set_auction_parameters(
+            &authority.pubkey(),
+            1_000,
+            11_001,
+            linear,
+        )],
+        &authority,
+    );
+    assert_program_error(result, 0, StablecointError::InvalidAuctionParameters);
+    let result = context.send(
+        &[client::set_auction_parameters(
+            &authority.pubkey(),
+            1_000,
+            5_000,
+            AuctionCurve::Linear { duration: 0 },
+        )],
+        &authority,
+    );
+    assert_program_error(result, 0, StablecointError::InvalidAuctionParameters);
+
+    let user = context.user(sol(1));
+    let result = context.send(
+        &[client::set_auction_parameters(
+            &user.pubkey(),
+            1_000,
+            5_000,
+            linear,
+        )],
+        &user,
+    );
+    assert!(result.is_err());
+
+    context
+        .send(
+            &[client::set_auction_parameters(
+                &authority.pubkey(),
+                500,
+                8_000,
+                linear,
+            )],
+            &authority,
+        )
+        .unwrap();
+    let config = context.config();
+    assert_eq!(config.auction_premium_bps, 500);
+    assert_eq!(config.auction_floor_bps, 8_000);
+    assert_eq!(config.auction_curve, linear);
+}
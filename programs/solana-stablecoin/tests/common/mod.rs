@@ -0,0 +1,456 @@
+//! In-process harness for the integration tests.
+//!
+//! Loads the program built by `anchor build` into LiteSVM and writes synthetic Pyth
+//! `PriceUpdateV2` accounts, so prices and their age can be set freely offline.
+#![allow(dead_code)]
+
+use anchor_lang::{AccountDeserialize, AccountSerialize, AnchorDeserialize};
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{
+        extension::StateWithExtensions,
+        state::{Account as SplTokenAccount, Mint as SplMint},
+    },
+};
+use litesvm::LiteSVM;
+use pyth_solana_receiver_sdk::price_update::{
+    get_feed_id_from_hex, PriceFeedMessage, PriceUpdateV2, VerificationLevel,
+};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use solana_stablecoin::{
+    error::StablecointError,
+    math::{AmountMode, OraclePrice},
+    state::{Auction, Collateral, Config},
+    SOL_USD_FEED_ID,
+};
+use solana_stablecoin_client::{
+    self as client, find_config_address, find_mint_address, PositionAddresses,
+};
+
+const PROGRAM_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../target/deploy/solana_stablecoin.so"
+);
+pub const START_TIMESTAMP: i64 = 1_700_000_000;
+pub const TOKEN_PROGRAM: Pubkey = token_2022::ID;
+
+/// Builds a price in the feed's 8 decimals from whole dollars.
+pub const fn usd_price(dollars: i64) -> i64 {
+    dollars * 100_000_000
+}
+
+/// Stablecoin base units (9 decimals) from whole dollars.
+pub const fn usd(dollars: u64) -> u64 {
+    dollars * 1_000_000_000
+}
+
+pub const fn sol(amount: u64) -> u64 {
+    amount * LAMPORTS_PER_SOL
+}
+
+pub struct TestContext {
+    pub svm: LiteSVM,
+    pub authority: Keypair,
+    pub recovery_authority: Keypair,
+    pub price_update: Pubkey,
+}
+
+impl TestContext {
+    /// Initialized stablecoin with SOL priced at `price` (in dollars).
+    pub fn new(price: i64) -> Self {
+        let mut context = Self::uninitialized(price);
+        let authority = context.authority.insecure_clone();
+        context
+            .send(
+                &[client::initialize_config(
+                    &authority.pubkey(),
+                    "Programmable Money".to_string(),
+                    "USDS".to_string(),
+                    "https://anchor-stablecoin.vercel.app/metadata.json".to_string(),
+                    context.recovery_authority.pubkey(),
+                )],
+                &authority,
+            )
+            .expect("initialize_config failed");
+        context
+    }
+
+    /// Loaded program and funded authority, before `initialize_config` ran.
+    pub fn uninitialized(price: i64) -> Self {
+        let mut svm = LiteSVM::new();
+        svm.add_program_from_file(solana_stablecoin::ID, PROGRAM_PATH)
+            .unwrap_or_else(|_| panic!("{PROGRAM_PATH} not found, run `anchor build` first"));
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = START_TIMESTAMP;
+        svm.set_sysvar(&clock);
+
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), sol(100)).unwrap();
+        let mut context = Self {
+            svm,
+            authority,
+            recovery_authority: Keypair::new(),
+            price_update: Pubkey::new_unique(),
+        };
+        context.set_price(usd_price(price), START_TIMESTAMP);
+        context
+    }
+
+    /// Funded keypair.
+    pub fn user(&mut self, lamports: u64) -> Keypair {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), lamports).unwrap();
+        user
+    }
+
+    /// Stablecoin at $100 with a borrower at health factor 1 and a liquidator, see
+    /// `borrower_position` and `liquidator`.
+    pub fn liquidation_setup() -> (Self, PositionAddresses, Keypair) {
+        let mut context = Self::new(100);
+        let position = context.borrower_position();
+        let liquidator = context.liquidator();
+        (context, position, liquidator)
+    }
+
+    /// A new borrower's position with 1 SOL backing $50, at health factor 1 for $100 SOL.
+    pub fn borrower_position(&mut self) -> PositionAddresses {
+        let borrower = self.user(sol(10));
+        self.open_and_mint(&borrower, 0, sol(1), usd(50)).unwrap()
+    }
+
+    /// A user holding $100 of stablecoin from an overcollateralized position of their own.
+    pub fn liquidator(&mut self) -> Keypair {
+        let liquidator = self.user(sol(100));
+        self.open_and_mint(&liquidator, 0, sol(50), usd(100))
+            .unwrap();
+        liquidator
+    }
+
+    /// Overwrites the price update account with `price` (8 decimals) published at
+    /// `publish_time`.
+    pub fn set_price(&mut self, price: i64, publish_time: i64) {
+        self.write_price_update(price_message(price, publish_time));
+    }
+
+    pub fn write_price_update(&mut self, price_message: PriceFeedMessage) {
+        let price_update = PriceUpdateV2 {
+            write_authority: Pubkey::new_unique(),
+            verification_level: VerificationLevel::Full,
+            price_message,
+            posted_slot: 0,
+        };
+        let mut data = Vec::new();
+        price_update.try_serialize(&mut data).unwrap();
+        let account = Account {
+            lamports: self.svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: pyth_solana_receiver_sdk::ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.svm.set_account(self.price_update, account).unwrap();
+    }
+
+    pub fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = unix_timestamp;
+        self.svm.set_sysvar(&clock);
+    }
+
+    pub fn send(
+        &mut self,
+        instructions: &[Instruction],
+        payer: &Keypair,
+    ) -> Result<(), TransactionError> {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(transaction);
+        // identical transactions would otherwise be rejected as already processed
+        self.svm.expire_blockhash();
+        result.map(|_| ()).map_err(|failed| failed.err)
+    }
+
+    /// Simulates a view instruction and decodes what it returned.
+    pub fn view<T: AnchorDeserialize>(&mut self, instruction: Instruction) -> T {
+        let payer = &self.authority;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            self.svm.latest_blockhash(),
+        );
+        let simulated = self
+            .svm
+            .simulate_transaction(transaction.into())
+            .unwrap_or_else(|failed| panic!("view failed: {:?}", failed.err));
+        T::try_from_slice(&simulated.return_data.data).unwrap()
+    }
+
+    /// Opens position `position_id` for `owner` and deposits and mints in one go.
+    pub fn open_and_mint(
+        &mut self,
+        owner: &Keypair,
+        position_id: u64,
+        amount_collateral: u64,
+        amount_to_mint: impl Into<AmountMode>,
+    ) -> Result<PositionAddresses, TransactionError> {
+        let position = PositionAddresses::new(&owner.pubkey(), position_id);
+        // one transaction, so a rejected deposit leaves no empty position behind
+        let instructions = [
+            client::open_position(&owner.pubkey(), position_id, &TOKEN_PROGRAM),
+            client::deposit_collateral_and_mint_tokens(
+                &owner.pubkey(),
+                &position,
+                &self.price_update,
+                &TOKEN_PROGRAM,
+                amount_collateral,
+                amount_to_mint.into(),
+            ),
+        ];
+        self.send(&instructions, owner)?;
+        Ok(position)
+    }
+
+    /// Blocklists `address`, the authority is still the compliance authority.
+    pub fn blocklist(&mut self, address: &Pubkey) -> Result<(), TransactionError> {
+        let authority = self.authority.insecure_clone();
+        let instruction = client::add_to_blocklist(&authority.pubkey(), *address);
+        self.send(&[instruction], &authority)
+    }
+
+    pub fn deposit(
+        &mut self,
+        owner: &Keypair,
+        position: &PositionAddresses,
+        amount_collateral: u64,
+        amount_to_mint: impl Into<AmountMode>,
+    ) -> Result<(), TransactionError> {
+        let instruction = client::deposit_collateral_and_mint_tokens(
+            &owner.pubkey(),
+            position,
+            &self.price_update,
+            &TOKEN_PROGRAM,
+            amount_collateral,
+            amount_to_mint.into(),
+        );
+        self.send(&[instruction], owner)
+    }
+
+    pub fn redeem(
+        &mut self,
+        owner: &Keypair,
+        position: &PositionAddresses,
+        amount_collateral: impl Into<AmountMode>,
+        amount_to_burn: u64,
+    ) -> Result<(), TransactionError> {
+        let instruction = client::redeem_collateral_and_burn_tokens(
+            &owner.pubkey(),
+            position,
+            &self.price_update,
+            &TOKEN_PROGRAM,
+            amount_collateral.into(),
+            amount_to_burn,
+        );
+        self.send(&[instruction], owner)
+    }
+
+    pub fn liquidate(
+        &mut self,
+        liquidator: &Keypair,
+        position: &PositionAddresses,
+        amount_to_burn: u64,
+    ) -> Result<(), TransactionError> {
+        let instruction = client::liquidate(
+            &liquidator.pubkey(),
+            position,
+            &self.price_update,
+            &TOKEN_PROGRAM,
+            amount_to_burn,
+        );
+        self.send(&[instruction], liquidator)
+    }
+
+    pub fn liquidate_batch(
+        &mut self,
+        liquidator: &Keypair,
+        positions: &[(PositionAddresses, u64)],
+    ) -> Result<(), TransactionError> {
+        let instruction = client::liquidate_batch(
+            &liquidator.pubkey(),
+            positions,
+            &self.price_update,
+            &TOKEN_PROGRAM,
+        );
+        self.send(&[instruction], liquidator)
+    }
+
+    pub fn start_auction(
+        &mut self,
+        starter: &Keypair,
+        position: &PositionAddresses,
+    ) -> Result<(), TransactionError> {
+        let instruction =
+            client::start_liquidation_auction(&starter.pubkey(), position, &self.price_update);
+        self.send(&[instruction], starter)
+    }
+
+    /// Bids on the position's running auction.
+    pub fn bid(
+        &mut self,
+        bidder: &Keypair,
+        position: &PositionAddresses,
+        amount_to_burn: u64,
+    ) -> Result<(), TransactionError> {
+        let starter = self.auction(position).starter;
+        let instruction = client::bid(
+            &bidder.pubkey(),
+            position,
+            &starter,
+            &TOKEN_PROGRAM,
+            amount_to_burn,
+        );
+        self.send(&[instruction], bidder)
+    }
+
+    pub fn auction(&self, position: &PositionAddresses) -> Auction {
+        self.anchor_account(&position.auction)
+    }
+
+    /// Sends `lamports` straight to `to`, bypassing the program.
+    pub fn donate(
+        &mut self,
+        from: &Keypair,
+        to: &Pubkey,
+        lamports: u64,
+    ) -> Result<(), TransactionError> {
+        let instruction = system_instruction::transfer(&from.pubkey(), to, lamports);
+        self.send(&[instruction], from)
+    }
+
+    pub fn sweep_donations(
+        &mut self,
+        position: &PositionAddresses,
+    ) -> Result<(), TransactionError> {
+        let authority = self.authority.insecure_clone();
+        let instruction = client::sweep_donations(&authority.pubkey(), position);
+        self.send(&[instruction], &authority)
+    }
+
+    pub fn set_keeper_tip(&mut self, keeper_tip: u64) -> Result<(), TransactionError> {
+        let authority = self.authority.insecure_clone();
+        let instruction = client::set_keeper_tip(&authority.pubkey(), keeper_tip);
+        self.send(&[instruction], &authority)
+    }
+
+    pub fn drip_savings(&mut self, keeper: &Keypair) -> Result<(), TransactionError> {
+        let instruction = client::drip_savings(&keeper.pubkey(), &TOKEN_PROGRAM);
+        self.send(&[instruction], keeper)
+    }
+
+    /// Rent-exempt minimum a SOL vault keeps besides the collateral.
+    pub fn vault_rent(&self) -> u64 {
+        self.svm.minimum_balance_for_rent_exemption(0)
+    }
+
+    /// The price currently stored in the price update account.
+    pub fn oracle_price(&self) -> OraclePrice {
+        let price_update: PriceUpdateV2 = self.anchor_account(&self.price_update);
+        OraclePrice::new(
+            price_update.price_message.price,
+            price_update.price_message.exponent,
+        )
+    }
+
+    pub fn config(&self) -> Config {
+        self.anchor_account(&find_config_address().0)
+    }
+
+    pub fn collateral(&self, position: &PositionAddresses) -> Collateral {
+        self.anchor_account(&position.collateral_account)
+    }
+
+    pub fn lamports(&self, address: &Pubkey) -> u64 {
+        self.svm.get_balance(address).unwrap_or_default()
+    }
+
+    pub fn stablecoin_balance(&self, owner: &Pubkey) -> u64 {
+        let address = client::stablecoin_token_address(owner, &TOKEN_PROGRAM);
+        let account = self.svm.get_account(&address).expect("no token account");
+        StateWithExtensions::<SplTokenAccount>::unpack(&account.data)
+            .unwrap()
+            .base
+            .amount
+    }
+
+    /// Position NFTs `holder` has for `position`, 0 when it has no token account.
+    pub fn position_nft_balance(&self, position: &PositionAddresses, holder: &Pubkey) -> u64 {
+        self.svm
+            .get_account(&position.position_token_account(holder))
+            .filter(|account| account.lamports > 0)
+            .map(|account| {
+                StateWithExtensions::<SplTokenAccount>::unpack(&account.data)
+                    .unwrap()
+                    .base
+                    .amount
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn stablecoin_supply(&self) -> u64 {
+        let account = self
+            .svm
+            .get_account(&find_mint_address().0)
+            .expect("no mint account");
+        StateWithExtensions::<SplMint>::unpack(&account.data)
+            .unwrap()
+            .base
+            .supply
+    }
+
+    fn anchor_account<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self.svm.get_account(address).expect("account not found");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+}
+
+/// SOL/USD message with `price` in 8 decimals and no confidence interval.
+pub fn price_message(price: i64, publish_time: i64) -> PriceFeedMessage {
+    PriceFeedMessage {
+        feed_id: get_feed_id_from_hex(SOL_USD_FEED_ID).unwrap(),
+        price,
+        conf: 0,
+        exponent: -8,
+        publish_time,
+        prev_publish_time: publish_time,
+        ema_price: price,
+        ema_conf: 0,
+    }
+}
+
+/// Asserts that the instruction at `index` failed with `expected`.
+pub fn assert_program_error(
+    result: Result<(), TransactionError>,
+    index: u8,
+    expected: StablecointError,
+) {
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            index,
+            InstructionError::Custom(expected.into())
+        ))
+    );
+}
@@ -0,0 +1,228 @@
+//! Random instruction sequences with invariants checked after every step.
+//!
+//! Users deposit, mint, redeem and liquidate each other while the price moves along a
+//! scripted path and outsiders send SOL straight to the vaults. Rejected instructions
+//! are fine, but whatever goes through must keep the accounting consistent.
+mod common;
+
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use common::*;
+use proptest::prelude::*;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_stablecoin::math;
+use solana_stablecoin_client::PositionAddresses;
+
+const USERS: usize = 4;
+
+#[derive(Debug, Clone)]
+enum Action {
+    Deposit {
+        user: usize,
+        collateral: u64,
+        mint: u64,
+    },
+    Redeem {
+        user: usize,
+        collateral: u64,
+        burn: u64,
+    },
+    Liquidate {
+        liquidator: usize,
+        target: usize,
+        burn: u64,
+    },
+    SetPrice {
+        dollars: i64,
+    },
+    Donate {
+        user: usize,
+        lamports: u64,
+    },
+    Sweep {
+        user: usize,
+    },
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    let user = 0..USERS;
+    // amounts in hundredths of a SOL or a dollar
+    let lamports = (0u64..500).prop_map(|amount| amount * LAMPORTS_PER_SOL / 100);
+    let stablecoin = (0u64..25_000).prop_map(|amount| amount * usd(1) / 100);
+    prop_oneof![
+        3 => (user.clone(), lamports.clone(), stablecoin.clone())
+            .prop_map(|(user, collateral, mint)| Action::Deposit { user, collateral, mint }),
+        2 => (user.clone(), lamports.clone(), stablecoin.clone())
+            .prop_map(|(user, collateral, burn)| Action::Redeem { user, collateral, burn }),
+        1 => (user.clone(), lamports)
+            .prop_map(|(user, lamports)| Action::Donate { user, lamports }),
+        1 => user.clone().prop_map(|user| Action::Sweep { user }),
+        2 => (user.clone(), user, stablecoin)
+            .prop_map(|(liquidator, target, burn)| Action::Liquidate { liquidator, target, burn }),
+        2 => (20i64..200).prop_map(|dollars| Action::SetPrice { dollars }),
+    ]
+}
+
+struct Simulation {
+    context: TestContext,
+    users: Vec<Keypair>,
+    positions: Vec<PositionAddresses>,
+    opened: Vec<bool>,
+    deposited: Vec<u64>,
+    withdrawn: Vec<u64>,
+    // lamports sent to each vault outside the program and not swept yet
+    donated: Vec<u64>,
+    donor: Keypair,
+}
+
+impl Simulation {
+    fn new() -> Self {
+        let mut context = TestContext::new(100);
+        let users: Vec<Keypair> = (0..USERS).map(|_| context.user(sol(1_000))).collect();
+        let donor = context.user(sol(10_000));
+        let positions = users
+            .iter()
+            .map(|user| PositionAddresses::new(&user.pubkey(), 0))
+            .collect();
+        Self {
+            context,
+            users,
+            positions,
+            opened: vec![false; USERS],
+            deposited: vec![0; USERS],
+            withdrawn: vec![0; USERS],
+            donated: vec![0; USERS],
+            donor,
+        }
+    }
+
+    fn vault(&self, user: usize) -> u64 {
+        self.context.lamports(&self.positions[user].sol_account)
+    }
+
+    fn apply(&mut self, action: &Action) -> Result<(), TestCaseError> {
+        match *action {
+            Action::Deposit {
+                user,
+                collateral,
+                mint,
+            } => {
+                let before = self.vault(user);
+                let owner = self.users[user].insecure_clone();
+                let result = if self.opened[user] {
+                    self.context
+                        .deposit(&owner, &self.positions[user], collateral, mint)
+                } else {
+                    self.context
+                        .open_and_mint(&owner, 0, collateral, mint)
+                        .map(|_| ())
+                };
+                if result.is_ok() {
+                    self.opened[user] = true;
+                }
+                self.deposited[user] += self.vault(user) - before;
+            }
+            Action::Redeem {
+                user,
+                collateral,
+                burn,
+            } => {
+                if !self.opened[user] {
+                    return Ok(());
+                }
+                let before = self.vault(user);
+                let owner = self.users[user].insecure_clone();
+                let _ = self
+                    .context
+                    .redeem(&owner, &self.positions[user], collateral, burn);
+                self.withdrawn[user] += before - self.vault(user);
+            }
+            Action::Liquidate {
+                liquidator,
+                target,
+                burn,
+            } => {
+                if !self.opened[target] {
+                    return Ok(());
+                }
+                let collateral = self.context.collateral(&self.positions[target]);
+                let config = self.context.config();
+                let health_factor = math::health_factor(
+                    collateral.lamport_balance,
+                    collateral.amount_minted,
+                    config.liquidation_threshold,
+                    self.context.oracle_price(),
+                )
+                .unwrap();
+                let liquidator = self.users[liquidator].insecure_clone();
+                let result = self
+                    .context
+                    .liquidate(&liquidator, &self.positions[target], burn);
+                prop_assert!(
+                    result.is_err() || health_factor < config.min_health_factor,
+                    "liquidated a position with health factor {}",
+                    health_factor
+                );
+            }
+            Action::SetPrice { dollars } => {
+                self.context.set_price(usd_price(dollars), START_TIMESTAMP);
+            }
+            Action::Donate { user, lamports } => {
+                // an unopened vault would count the donation towards its rent reserve
+                if !self.opened[user] {
+                    return Ok(());
+                }
+                let donor = self.donor.insecure_clone();
+                let vault = self.positions[user].sol_account;
+                if self.context.donate(&donor, &vault, lamports).is_ok() {
+                    self.donated[user] += lamports;
+                }
+            }
+            Action::Sweep { user } => {
+                if !self.opened[user] {
+                    return Ok(());
+                }
+                let result = self.context.sweep_donations(&self.positions[user]);
+                prop_assert!(result.is_ok(), "sweep failed: {:?}", result);
+                self.donated[user] = 0;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_invariants(&self) -> Result<(), TestCaseError> {
+        let mut total_minted = 0;
+        for user in 0..USERS {
+            prop_assert!(
+                self.withdrawn[user] <= self.deposited[user],
+                "user {} withdrew {} but deposited {}",
+                user,
+                self.withdrawn[user],
+                self.deposited[user]
+            );
+            if !self.opened[user] {
+                continue;
+            }
+            let collateral = self.context.collateral(&self.positions[user]);
+            prop_assert_eq!(
+                collateral.lamport_balance + self.context.vault_rent() + self.donated[user],
+                self.vault(user)
+            );
+            total_minted += collateral.amount_minted;
+        }
+        prop_assert_eq!(self.context.stablecoin_supply(), total_minted);
+        Ok(())
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn invariants_hold_across_sequences(actions in prop::collection::vec(action(), 1..40)) {
+        let mut simulation = Simulation::new();
+        for action in &actions {
+            simulation.apply(action)?;
+            simulation.check_invariants()?;
+        }
+    }
+}
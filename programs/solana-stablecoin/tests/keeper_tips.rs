@@ -0,0 +1,187 @@
+mod common;
+
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+use common::*;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_stablecoin::{
+    error::StablecointError, state::SavingsVault, ACCRUAL_TIP_INTERVAL, KEEPER_TIP,
+    MAX_AUCTION_TIP_BPS, MAX_KEEPER_TIP,
+};
+use solana_stablecoin_client::{self as client, find_savings_vault_address, find_treasury_address};
+
+/// A savings vault started at `START_TIMESTAMP` with $100 saved in it and a treasury
+/// holding `treasury` lamports.
+fn setup(treasury: u64) -> (TestContext, Keypair) {
+    let (mut context, keeper) = setup_without_savers(treasury);
+    // shares written straight into the vault, depositing would need the transfer hook
+    let address = find_savings_vault_address().0;
+    let mut account = context.svm.get_account(&address).unwrap();
+    let mut savings_vault = SavingsVault::try_deserialize(&mut account.data.as_slice()).unwrap();
+    savings_vault.total_shares = usd(100);
+    savings_vault
+        .try_serialize(&mut account.data.as_mut_slice())
+        .unwrap();
+    context.svm.set_account(address, account).unwrap();
+    (context, keeper)
+}
+
+fn setup_without_savers(treasury: u64) -> (TestContext, Keypair) {
+    let mut context = TestContext::new(100);
+    let authority = context.authority.insecure_clone();
+    context
+        .send(
+            &[client::initialize_savings_vault(
+                &authority.pubkey(),
+                &TOKEN_PROGRAM,
+                500,
+                usd(1_000),
+            )],
+            &authority,
+        )
+        .unwrap();
+    if treasury > 0 {
+        context
+            .donate(&authority, &find_treasury_address().0, treasury)
+            .unwrap();
+    }
+    let keeper = context.user(sol(1));
+    (context, keeper)
+}
+
+#[test]
+fn drip_pays_the_tip_once_the_interval_passed() {
+    let (mut context, keeper) = setup(sol(1));
+    let treasury = find_treasury_address().0;
+
+    context.warp_to(START_TIMESTAMP + ACCRUAL_TIP_INTERVAL);
+    context.drip_savings(&keeper).unwrap();
+    assert_eq!(
+        context.lamports(&keeper.pubkey()),
+        sol(1) + KEEPER_TIP - 5_000
+    );
+    assert_eq!(context.lamports(&treasury), sol(1) - KEEPER_TIP);
+
+    // the vault is current again, so the next drip is not worth a tip
+    context.warp_to(START_TIMESTAMP + ACCRUAL_TIP_INTERVAL + 60);
+    context.drip_savings(&keeper).unwrap();
+    assert_eq!(
+        context.lamports(&keeper.pubkey()),
+        sol(1) + KEEPER_TIP - 10_000
+    );
+    assert_eq!(context.lamports(&treasury), sol(1) - KEEPER_TIP);
+}
+
+#[test]
+fn drip_before_the_interval_pays_nothing() {
+    let (mut context, keeper) = setup(sol(1));
+    context.warp_to(START_TIMESTAMP + ACCRUAL_TIP_INTERVAL - 1);
+    context.drip_savings(&keeper).unwrap();
+    assert_eq!(context.lamports(&keeper.pubkey()), sol(1) - 5_000);
+    assert_eq!(context.lamports(&find_treasury_address().0), sol(1));
+}
+
+#[test]
+fn drip_with_nothing_to_accrue_pays_nothing() {
+    let (mut context, keeper) = setup_without_savers(sol(1));
+    context.warp_to(START_TIMESTAMP + ACCRUAL_TIP_INTERVAL);
+    context.drip_savings(&keeper).unwrap();
+    assert_eq!(context.lamports(&keeper.pubkey()), sol(1) - 5_000);
+    assert_eq!(context.lamports(&find_treasury_address().0), sol(1));
+}
+
+#[test]
+fn treasury_pays_what_it_can_above_rent() {
+    let (mut context, keeper) = setup(0);
+    context.warp_to(START_TIMESTAMP + ACCRUAL_TIP_INTERVAL);
+    // an empty treasury doesn't hold up the accrual
+    context.drip_savings(&keeper).unwrap();
+    assert_eq!(context.lamports(&keeper.pubkey()), sol(1) - 5_000);
+
+    let authority = context.authority.insecure_clone();
+    let treasury = find_treasury_address().0;
+    let funded = context.vault_rent() + 1_000;
+    context.donate(&authority, &treasury, funded).unwrap();
+    context.warp_to(START_TIMESTAMP + 2 * ACCRUAL_TIP_INTERVAL);
+    context.drip_savings(&keeper).unwrap();
+    assert_eq!(context.lamports(&keeper.pubkey()), sol(1) + 1_000 - 10_000);
+    assert_eq!(context.lamports(&treasury), context.vault_rent());
+}
+
+#[test]
+fn keeper_tip_is_configurable() {
+    let (mut context, keeper) = setup(sol(1));
+    context.set_keeper_tip(5_000_000).unwrap();
+    assert_eq!(context.config().keeper_tip, 5_000_000);
+
+    context.warp_to(START_TIMESTAMP + ACCRUAL_TIP_INTERVAL);
+    context.drip_savings(&keeper).unwrap();
+    assert_eq!(
+        context.lamports(&keeper.pubkey()),
+        sol(1) + 5_000_000 - 5_000
+    );
+
+    // a zero tip turns the payouts off
+    context.set_keeper_tip(0).unwrap();
+    context.warp_to(START_TIMESTAMP + 2 * ACCRUAL_TIP_INTERVAL);
+    context.drip_savings(&keeper).unwrap();
+    assert_eq!(
+        context.lamports(&keeper.pubkey()),
+        sol(1) + 5_000_000 - 10_000
+    );
+
+    let result = context.send(
+        &[client::set_keeper_tip(&keeper.pubkey(), KEEPER_TIP)],
+        &keeper,
+    );
+    assert!(result.is_err());
+    assert_eq!(context.config().keeper_tip, 0);
+}
+
+#[test]
+fn keeper_tip_has_a_maximum() {
+    let (mut context, _) = setup(0);
+    let result = context.set_keeper_tip(MAX_KEEPER_TIP + 1);
+    assert_program_error(result, 0, StablecointError::InvalidKeeperTip);
+    context.set_keeper_tip(MAX_KEEPER_TIP).unwrap();
+    assert_eq!(context.config().keeper_tip, MAX_KEEPER_TIP);
+}
+
+#[test]
+fn blocklisted_keeper_cannot_drip() {
+    let (mut context, keeper) = setup(sol(1));
+    let authority = context.authority.insecure_clone();
+    context
+        .send(
+            &[client::add_to_blocklist(
+                &authority.pubkey(),
+                keeper.pubkey(),
+            )],
+            &authority,
+        )
+        .unwrap();
+    context.warp_to(START_TIMESTAMP + ACCRUAL_TIP_INTERVAL);
+    let result = context.drip_savings(&keeper);
+    assert_program_error(result, 0, StablecointError::Blocklisted);
+}
+
+#[test]
+fn auction_tip_is_a_small_share_of_the_seized_collateral() {
+    let mut context = TestContext::new(100);
+    let borrower = context.user(sol(10));
+    let position = context
+        .open_and_mint(&borrower, 0, sol(1) / 2, usd(25))
+        .unwrap();
+    context.set_keeper_tip(MAX_KEEPER_TIP).unwrap();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+
+    let starter = context.user(sol(1));
+    context.start_auction(&starter, &position).unwrap();
+    // 1% of the half SOL, below the configured tip
+    let tip = sol(1) / 2 * MAX_AUCTION_TIP_BPS / 10_000;
+    assert!(tip < MAX_KEEPER_TIP);
+    assert_eq!(context.auction(&position).lamports, sol(1) / 2 - tip);
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        context.vault_rent()
+    );
+}
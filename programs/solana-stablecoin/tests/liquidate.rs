@@ -0,0 +1,99 @@
+mod common;
+
+use common::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+use solana_stablecoin::error::StablecointError;
+use solana_stablecoin_client::{self as client, PositionAddresses};
+
+fn liquidate(
+    context: &mut TestContext,
+    position: &PositionAddresses,
+    liquidator: &Keypair,
+    amount_to_burn: u64,
+) -> Result<(), TransactionError> {
+    let price_update = context.price_update;
+    context.send(
+        &[client::liquidate(
+            &liquidator.pubkey(),
+            position,
+            &price_update,
+            &TOKEN_PROGRAM,
+            amount_to_burn,
+        )],
+        liquidator,
+    )
+}
+
+#[test]
+fn liquidate_unhealthy_position() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    let lamports_before = context.lamports(&liquidator.pubkey());
+
+    liquidate(&mut context, &position, &liquidator, usd(20)).unwrap();
+
+    // $20 at $90 is 222_222_222 lamports, plus the 10% bonus
+    let seized = 222_222_222 + 22_222_222;
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.amount_minted, usd(30));
+    assert_eq!(collateral.lamport_balance, sol(1) - seized);
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        sol(1) - seized + context.vault_rent()
+    );
+    assert_eq!(context.stablecoin_balance(&liquidator.pubkey()), usd(80));
+    // minus the 5000 lamport transaction fee
+    assert_eq!(
+        context.lamports(&liquidator.pubkey()),
+        lamports_before + seized - 5_000
+    );
+}
+
+#[test]
+fn liquidate_healthy_position_fails() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    let result = liquidate(&mut context, &position, &liquidator, usd(20));
+    assert_program_error(result, 0, StablecointError::AboveMinimumHealthFactor);
+}
+
+#[test]
+fn liquidation_leaving_position_unhealthy_fails() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    // burning $5 only brings the health factor from 45/50 to 42/45
+    let result = liquidate(&mut context, &position, &liquidator, usd(5));
+    assert_program_error(result, 0, StablecointError::BelowMinimumHealthFactor);
+}
+
+#[test]
+fn liquidate_more_than_collateral_fails() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    context.set_price(usd_price(40), START_TIMESTAMP);
+    // $50 at $40 plus the bonus is 1.375 SOL, more than the position holds
+    let result = liquidate(&mut context, &position, &liquidator, usd(50));
+    assert_program_error(result, 0, StablecointError::InsufficientCollateral);
+    assert_eq!(context.collateral(&position).amount_minted, usd(50));
+}
+
+#[test]
+fn liquidate_with_stale_price_fails() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    context.warp_to(START_TIMESTAMP + solana_stablecoin::MAXIMUM_AGE as i64 + 1);
+    assert!(liquidate(&mut context, &position, &liquidator, usd(20)).is_err());
+}
+
+#[test]
+fn liquidation_after_raising_minimum_health_factor() {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    let authority = context.authority.insecure_clone();
+    context
+        .send(&[client::update_config(&authority.pubkey(), 2)], &authority)
+        .unwrap();
+    // at $100 the position is at health factor 1, and burning all of it clears the debt
+    liquidate(&mut context, &position, &liquidator, usd(50)).unwrap();
+    assert_eq!(context.collateral(&position).amount_minted, 0);
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use common::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+use solana_stablecoin::error::StablecointError;
+use solana_stablecoin_client::{self as client, PositionAddresses};
+
+// accounts of `LiquidateBatch` before the (collateral, sol vault) pairs
+const FIXED_ACCOUNTS: usize = 8;
+
+/// Two borrowers at health factor 1 for $100 SOL, and a liquidator holding $100 of
+/// stablecoin.
+fn setup() -> (TestContext, [PositionAddresses; 2], Keypair) {
+    let (mut context, position, liquidator) = TestContext::liquidation_setup();
+    let positions = [position, context.borrower_position()];
+    (context, positions, liquidator)
+}
+
+#[test]
+fn liquidates_every_position_in_the_batch() {
+    let (mut context, positions, liquidator) = setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    let lamports_before = context.lamports(&liquidator.pubkey());
+    let supply_before = context.stablecoin_supply();
+
+    context
+        .liquidate_batch(
+            &liquidator,
+            &[(positions[0], usd(20)), (positions[1], usd(30))],
+        )
+        .unwrap();
+
+    // $20 and $30 at $90, plus the 10% bonus
+    let seized = [222_222_222 + 22_222_222, 333_333_333 + 33_333_333];
+    for (position, (seized, debt)) in positions.iter().zip(seized.iter().zip([30, 20])) {
+        let collateral = context.collateral(position);
+        assert_eq!(collateral.amount_minted, usd(debt));
+        assert_eq!(collateral.lamport_balance, sol(1) - seized);
+        assert_eq!(
+            context.lamports(&position.sol_account),
+            sol(1) - seized + context.vault_rent()
+        );
+    }
+    assert_eq!(context.stablecoin_balance(&liquidator.pubkey()), usd(50));
+    assert_eq!(context.stablecoin_supply(), supply_before - usd(50));
+    assert_eq!(
+        context.lamports(&liquidator.pubkey()),
+        lamports_before + seized[0] + seized[1] - 5_000
+    );
+}
+
+#[test]
+fn healthy_position_fails_the_whole_batch() {
+    let (mut context, positions, liquidator) = setup();
+    let borrower = context.user(sol(10));
+    let healthy = context
+        .open_and_mint(&borrower, 0, sol(2), usd(50))
+        .unwrap();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+
+    let result =
+        context.liquidate_batch(&liquidator, &[(positions[0], usd(20)), (healthy, usd(20))]);
+    assert_program_error(result, 0, StablecointError::AboveMinimumHealthFactor);
+    assert_eq!(context.collateral(&positions[0]).amount_minted, usd(50));
+    assert_eq!(context.stablecoin_balance(&liquidator.pubkey()), usd(100));
+}
+
+#[test]
+fn position_listed_twice_is_reread() {
+    let (mut context, positions, liquidator) = setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    // the first liquidation already brings the position back to health
+    let result = context.liquidate_batch(
+        &liquidator,
+        &[(positions[0], usd(20)), (positions[0], usd(20))],
+    );
+    assert_program_error(result, 0, StablecointError::AboveMinimumHealthFactor);
+}
+
+#[test]
+fn vault_of_another_position_is_rejected() {
+    let (mut context, positions, liquidator) = setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    let mut instruction = client::liquidate_batch(
+        &liquidator.pubkey(),
+        &[(positions[0], usd(20))],
+        &context.price_update,
+        &TOKEN_PROGRAM,
+    );
+    instruction.accounts[FIXED_ACCOUNTS + 1].pubkey = positions[1].sol_account;
+
+    let result = context.send(&[instruction], &liquidator);
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
+        ))
+    );
+}
+
+#[test]
+fn accounts_must_pair_up_with_amounts() {
+    let (mut context, positions, liquidator) = setup();
+    context.set_price(usd_price(90), START_TIMESTAMP);
+    let mut instruction = client::liquidate_batch(
+        &liquidator.pubkey(),
+        &[(positions[0], usd(20)), (positions[1], usd(20))],
+        &context.price_update,
+        &TOKEN_PROGRAM,
+    );
+    instruction.accounts.truncate(FIXED_ACCOUNTS + 2);
+    let result = context.send(&[instruction], &liquidator);
+    assert_program_error(result, 0, StablecointError::InvalidLiquidationBatch);
+
+    let result = context.liquidate_batch(&liquidator, &[]);
+    assert_program_error(result, 0, StablecointError::InvalidLiquidationBatch);
+}
@@ -0,0 +1,823 @@
+//! Unit and property tests of the risk math in `solana_stablecoin::math`.
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use proptest::prelude::*;
+use solana_stablecoin::{
+    error::StablecointError,
+    math::{
+        auction_fill, auction_price, health_factor, is_liquidatable, lamports_from_usd,
+        liquidate_position, liquidation_price, max_debt, max_withdrawable, mint_amount, mul_div,
+        scale_price, usd_value, validate_auction_parameters, withdraw_amount, AmountMode,
+        AuctionCurve, AuctionFill, LiquidationOutcome, OraclePrice, Rounding,
+    },
+    LIQUIDATION_BONUS, LIQUIDATION_THRESHOLD, MIN_HEALTH_FACTOR,
+};
+
+const PRICE_SCALE: u128 = 100_000_000; // Pyth publishes SOL/USD with exponent -8
+const USD: u64 = 1_000_000_000;
+
+fn sol_usd(price: i64) -> OraclePrice {
+    OraclePrice::new(price, -8)
+}
+
+fn dollars(amount: i64) -> OraclePrice {
+    sol_usd(amount * PRICE_SCALE as i64)
+}
+
+fn is_error<T: PartialEq + std::fmt::Debug>(
+    result: anchor_lang::Result<T>,
+    expected: StablecointError,
+) -> bool {
+    result == Err(expected.into())
+}
+
+// ============================================================================
+// Examples
+// ============================================================================
+
+#[test]
+fn mul_div_rounds_as_asked() {
+    assert_eq!(mul_div(7, 3, 2, Rounding::Down).unwrap(), 10);
+    assert_eq!(mul_div(7, 3, 2, Rounding::Up).unwrap(), 11);
+    assert_eq!(mul_div(8, 3, 2, Rounding::Up).unwrap(), 12);
+    assert!(is_error(
+        mul_div(1, 1, 0, Rounding::Down),
+        StablecointError::MathError
+    ));
+    assert!(is_error(
+        mul_div(u64::MAX as u128, 2, 1, Rounding::Down),
+        StablecointError::MathError
+    ));
+    assert!(is_error(
+        mul_div(u128::MAX, 2, 1, Rounding::Down),
+        StablecointError::MathError
+    ));
+}
+
+#[test]
+fn usd_value_examples() {
+    assert_eq!(
+        usd_value(LAMPORTS_PER_SOL, dollars(100)).unwrap(),
+        100 * USD
+    );
+    assert_eq!(
+        usd_value(LAMPORTS_PER_SOL / 2, dollars(150)).unwrap(),
+        75 * USD
+    );
+    assert_eq!(usd_value(0, dollars(150)).unwrap(), 0);
+    // 1 lamport at $150.12345678 is worth 150.12345678 base units, rounded down
+    assert_eq!(usd_value(1, sol_usd(150_12345678)).unwrap(), 150);
+    // the same price with other exponents
+    assert_eq!(
+        usd_value(LAMPORTS_PER_SOL, OraclePrice::new(100, 0)).unwrap(),
+        100 * USD
+    );
+    assert_eq!(
+        usd_value(LAMPORTS_PER_SOL, OraclePrice::new(1, 2)).unwrap(),
+        100 * USD
+    );
+    assert_eq!(
+        usd_value(LAMPORTS_PER_SOL, OraclePrice::new(10_000, -2)).unwrap(),
+        100 * USD
+    );
+}
+
+#[test]
+fn lamports_from_usd_examples() {
+    assert_eq!(
+        lamports_from_usd(100 * USD, dollars(100)).unwrap(),
+        LAMPORTS_PER_SOL
+    );
+    assert_eq!(
+        lamports_from_usd(20 * USD, dollars(90)).unwrap(),
+        222_222_222
+    );
+    // $1 at $3 per SOL is 0.333... SOL, rounded down
+    assert_eq!(lamports_from_usd(USD, dollars(3)).unwrap(), 333_333_333);
+    assert_eq!(
+        lamports_from_usd(USD, OraclePrice::new(3, 0)).unwrap(),
+        333_333_333
+    );
+    assert_eq!(
+        lamports_from_usd(100 * USD, OraclePrice::new(1, 2)).unwrap(),
+        LAMPORTS_PER_SOL
+    );
+}
+
+#[test]
+fn invalid_prices_are_rejected() {
+    for price in [
+        sol_usd(0),
+        sol_usd(-1),
+        OraclePrice::new(1, 19),
+        OraclePrice::new(1, -19),
+    ] {
+        assert!(is_error(
+            usd_value(1, price),
+            StablecointError::InvalidPrice
+        ));
+        assert!(is_error(
+            lamports_from_usd(1, price),
+            StablecointError::InvalidPrice
+        ));
+        assert!(is_error(
+            health_factor(1, 1, LIQUIDATION_THRESHOLD, price),
+            StablecointError::InvalidPrice
+        ));
+        assert!(is_error(
+            liquidate_position(1, 1, 1, LIQUIDATION_BONUS, price),
+            StablecointError::InvalidPrice
+        ));
+    }
+}
+
+#[test]
+fn overflowing_values_fail() {
+    assert!(is_error(
+        usd_value(u64::MAX, dollars(100)),
+        StablecointError::MathError
+    ));
+    assert!(is_error(
+        lamports_from_usd(u64::MAX, sol_usd(1)),
+        StablecointError::MathError
+    ));
+}
+
+#[test]
+fn health_factor_examples() {
+    let threshold = LIQUIDATION_THRESHOLD;
+    // 1 SOL at $100 with a 50% threshold backs $50
+    assert_eq!(
+        health_factor(LAMPORTS_PER_SOL, 50 * USD, threshold, dollars(100)).unwrap(),
+        1
+    );
+    assert_eq!(
+        health_factor(LAMPORTS_PER_SOL, 25 * USD, threshold, dollars(100)).unwrap(),
+        2
+    );
+    // 49.99... / 50 rounds down to 0
+    assert_eq!(
+        health_factor(LAMPORTS_PER_SOL - 1, 50 * USD, threshold, dollars(100)).unwrap(),
+        0
+    );
+    assert_eq!(
+        health_factor(LAMPORTS_PER_SOL, 50 * USD + 1, threshold, dollars(100)).unwrap(),
+        0
+    );
+    assert_eq!(
+        health_factor(0, 0, threshold, dollars(100)).unwrap(),
+        u64::MAX
+    );
+    // the price is still checked when nothing is minted
+    assert!(health_factor(LAMPORTS_PER_SOL, 0, threshold, sol_usd(0)).is_err());
+}
+
+#[test]
+fn is_liquidatable_below_minimum() {
+    assert!(is_liquidatable(0, MIN_HEALTH_FACTOR));
+    assert!(!is_liquidatable(MIN_HEALTH_FACTOR, MIN_HEALTH_FACTOR));
+    assert!(!is_liquidatable(u64::MAX, MIN_HEALTH_FACTOR));
+}
+
+#[test]
+fn liquidate_position_examples() {
+    // $20 at $90 is 222_222_222 lamports, plus the 10% bonus rounded down
+    assert_eq!(
+        liquidate_position(LAMPORTS_PER_SOL, 50 * USD, 20 * USD, 10, dollars(90)).unwrap(),
+        LiquidationOutcome {
+            lamports_paid: 244_444_444,
+            lamport_balance: 755_555_556,
+            amount_minted: 30 * USD,
+        }
+    );
+    // 9 lamports earn no bonus at 10%
+    assert_eq!(
+        liquidate_position(9, 100, 9, 10, OraclePrice::new(1, 0))
+            .unwrap()
+            .lamports_paid,
+        9
+    );
+    assert!(is_error(
+        liquidate_position(LAMPORTS_PER_SOL, 50 * USD, 50 * USD, 10, dollars(40)),
+        StablecointError::InsufficientCollateral
+    ));
+    assert!(is_error(
+        liquidate_position(LAMPORTS_PER_SOL, 10 * USD, 11 * USD, 10, dollars(100)),
+        StablecointError::MathError
+    ));
+}
+
+#[test]
+fn max_debt_examples() {
+    // 2 SOL at $100 with a 50% threshold
+    assert_eq!(
+        max_debt(2 * LAMPORTS_PER_SOL, 50, 1, dollars(100)).unwrap(),
+        100 * USD
+    );
+    assert_eq!(
+        max_debt(2 * LAMPORTS_PER_SOL, 50, 3, dollars(100)).unwrap(),
+        33_333_333_333
+    );
+    assert_eq!(
+        max_debt(2 * LAMPORTS_PER_SOL, 50, 0, dollars(100)).unwrap(),
+        u64::MAX
+    );
+    assert_eq!(max_debt(0, 50, 1, dollars(100)).unwrap(), 0);
+}
+
+#[test]
+fn max_withdrawable_examples() {
+    let balance = 2 * LAMPORTS_PER_SOL;
+    assert_eq!(
+        max_withdrawable(balance, 50 * USD, 50, 1, dollars(100)).unwrap(),
+        LAMPORTS_PER_SOL
+    );
+    assert_eq!(
+        max_withdrawable(balance, 0, 50, 1, dollars(100)).unwrap(),
+        balance
+    );
+    assert_eq!(
+        max_withdrawable(balance, 50 * USD, 50, 0, dollars(100)).unwrap(),
+        balance
+    );
+    assert_eq!(
+        max_withdrawable(balance, 100 * USD + 1, 50, 1, dollars(100)).unwrap(),
+        0
+    );
+    assert_eq!(max_withdrawable(balance, 1, 0, 1, dollars(100)).unwrap(), 0);
+    // $1 at $3 per SOL needs 0.666... SOL at 50%, rounded up
+    assert_eq!(
+        max_withdrawable(balance, USD, 50, 1, dollars(3)).unwrap(),
+        balance - 666_666_667
+    );
+    assert!(is_error(
+        max_withdrawable(balance, 0, 50, 1, sol_usd(0)),
+        StablecointError::InvalidPrice
+    ));
+}
+
+#[test]
+fn mint_amount_examples() {
+    let balance = 2 * LAMPORTS_PER_SOL;
+    assert_eq!(
+        mint_amount(AmountMode::from(7), balance, 0, 50, 1, dollars(100)).unwrap(),
+        7
+    );
+    assert_eq!(
+        mint_amount(AmountMode::All, balance, 0, 50, 1, dollars(100)).unwrap(),
+        100 * USD
+    );
+    assert_eq!(
+        mint_amount(AmountMode::All, balance, 30 * USD, 50, 1, dollars(100)).unwrap(),
+        70 * USD
+    );
+    let max_safe = AmountMode::MaxSafe { buffer_bps: 1_000 };
+    assert_eq!(
+        mint_amount(max_safe, balance, 0, 50, 1, dollars(100)).unwrap(),
+        90 * USD
+    );
+    assert_eq!(
+        mint_amount(max_safe, balance, 95 * USD, 50, 1, dollars(100)).unwrap(),
+        0
+    );
+    assert_eq!(
+        mint_amount(
+            AmountMode::MaxSafe { buffer_bps: 10_000 },
+            balance,
+            0,
+            50,
+            1,
+            dollars(100)
+        )
+        .unwrap(),
+        0
+    );
+    assert!(is_error(
+        mint_amount(
+            AmountMode::MaxSafe { buffer_bps: 10_001 },
+            balance,
+            0,
+            50,
+            1,
+            dollars(100)
+        ),
+        StablecointError::InvalidAmountBuffer
+    ));
+}
+
+#[test]
+fn withdraw_amount_examples() {
+    let balance = 3 * LAMPORTS_PER_SOL;
+    assert_eq!(
+        withdraw_amount(AmountMode::from(7), balance, 50 * USD, 50, 1, dollars(100)).unwrap(),
+        7
+    );
+    assert_eq!(
+        withdraw_amount(AmountMode::All, balance, 50 * USD, 50, 1, dollars(100)).unwrap(),
+        balance
+    );
+    assert_eq!(
+        withdraw_amount(
+            AmountMode::MaxSafe { buffer_bps: 0 },
+            balance,
+            50 * USD,
+            50,
+            1,
+            dollars(100)
+        )
+        .unwrap(),
+        2 * LAMPORTS_PER_SOL
+    );
+    // keeps 1 SOL plus 10%
+    assert_eq!(
+        withdraw_amount(
+            AmountMode::MaxSafe { buffer_bps: 1_000 },
+            balance,
+            50 * USD,
+            50,
+            1,
+            dollars(100)
+        )
+        .unwrap(),
+        19 * LAMPORTS_PER_SOL / 10
+    );
+    assert_eq!(
+        withdraw_amount(
+            AmountMode::MaxSafe { buffer_bps: 1_000 },
+            balance,
+            200 * USD,
+            50,
+            1,
+            dollars(100)
+        )
+        .unwrap(),
+        0
+    );
+    assert!(is_error(
+        withdraw_amount(
+            AmountMode::MaxSafe { buffer_bps: 10_001 },
+            balance,
+            0,
+            50,
+            1,
+            dollars(100)
+        ),
+        StablecointError::InvalidAmountBuffer
+    ));
+}
+
+#[test]
+fn liquidation_price_examples() {
+    // 2 SOL backing $50 at 50% is liquidatable below $50
+    assert_eq!(
+        liquidation_price(2 * LAMPORTS_PER_SOL, 50 * USD, 50, 1, -8).unwrap(),
+        50 * PRICE_SCALE as i64
+    );
+    assert_eq!(
+        liquidation_price(2 * LAMPORTS_PER_SOL, 50 * USD, 50, 1, 0).unwrap(),
+        50
+    );
+    // $1 against 3 SOL needs $2 of collateral, so $0.666... per SOL rounded up
+    assert_eq!(
+        liquidation_price(3 * LAMPORTS_PER_SOL, USD, 50, 1, -2).unwrap(),
+        67
+    );
+    assert_eq!(
+        liquidation_price(LAMPORTS_PER_SOL, 0, 50, 1, -8).unwrap(),
+        0
+    );
+    assert_eq!(
+        liquidation_price(LAMPORTS_PER_SOL, USD, 50, 0, -8).unwrap(),
+        0
+    );
+    assert_eq!(liquidation_price(0, USD, 50, 1, -8).unwrap(), i64::MAX);
+    assert_eq!(
+        liquidation_price(LAMPORTS_PER_SOL, USD, 0, 1, -8).unwrap(),
+        i64::MAX
+    );
+    assert!(is_error(
+        liquidation_price(LAMPORTS_PER_SOL, USD, 50, 1, 19),
+        StablecointError::InvalidPrice
+    ));
+}
+
+#[test]
+fn scale_price_rounds_up() {
+    assert_eq!(scale_price(dollars(90), 11_000).unwrap(), dollars(99));
+    assert_eq!(scale_price(sol_usd(3), 5_000).unwrap(), sol_usd(2));
+    assert!(is_error(
+        scale_price(sol_usd(0), 5_000),
+        StablecointError::InvalidPrice
+    ));
+}
+
+#[test]
+fn linear_auction_price_examples() {
+    let curve = AuctionCurve::Linear { duration: 3_600 };
+    assert_eq!(auction_price(99, 45, 0, curve).unwrap(), 99);
+    assert_eq!(auction_price(99, 45, -10, curve).unwrap(), 99);
+    assert_eq!(auction_price(99, 45, 1_800, curve).unwrap(), 72);
+    // 54 * 1 / 3600 of decay rounds down, so the price stays put
+    assert_eq!(auction_price(99, 45, 1, curve).unwrap(), 99);
+    assert_eq!(auction_price(99, 45, 3_600, curve).unwrap(), 45);
+    assert_eq!(auction_price(99, 45, i64::MAX, curve).unwrap(), 45);
+    assert!(is_error(
+        auction_price(45, 99, 0, curve),
+        StablecointError::InvalidPrice
+    ));
+}
+
+#[test]
+fn exponential_auction_price_examples() {
+    let curve = AuctionCurve::Exponential {
+        step: 60,
+        cut_bps: 1_000,
+    };
+    let start = 10_000_000_000;
+    assert_eq!(auction_price(start, 1, 59, curve).unwrap(), start);
+    assert_eq!(auction_price(start, 1, 60, curve).unwrap(), 9_000_000_000);
+    assert_eq!(auction_price(start, 1, 120, curve).unwrap(), 8_100_000_000);
+    assert_eq!(auction_price(start, 1, 180, curve).unwrap(), 7_290_000_000);
+    assert_eq!(
+        auction_price(start, 5_000_000_000, 600, curve).unwrap(),
+        5_000_000_000
+    );
+    // a whole cut drops straight to the floor, an endless auction stays there
+    let cut_all = AuctionCurve::Exponential {
+        step: 1,
+        cut_bps: 10_000,
+    };
+    assert_eq!(auction_price(start, 7, 1, cut_all).unwrap(), 7);
+    assert_eq!(auction_price(start, 7, i64::MAX, curve).unwrap(), 7);
+}
+
+#[test]
+fn auction_fill_examples() {
+    let price = dollars(100);
+    // $50 buys half a SOL
+    assert_eq!(
+        auction_fill(LAMPORTS_PER_SOL, 80 * USD, 50 * USD, price).unwrap(),
+        AuctionFill {
+            amount_burned: 50 * USD,
+            lamports_paid: LAMPORTS_PER_SOL / 2,
+        }
+    );
+    // no more than the debt is burned
+    assert_eq!(
+        auction_fill(LAMPORTS_PER_SOL, 20 * USD, 50 * USD, price).unwrap(),
+        AuctionFill {
+            amount_burned: 20 * USD,
+            lamports_paid: LAMPORTS_PER_SOL / 5,
+        }
+    );
+    // the last of the collateral costs only what it is worth
+    assert_eq!(
+        auction_fill(LAMPORTS_PER_SOL, 500 * USD, 500 * USD, price).unwrap(),
+        AuctionFill {
+            amount_burned: 100 * USD,
+            lamports_paid: LAMPORTS_PER_SOL,
+        }
+    );
+    // and its value rounds up: 1 lamport at $100 is worth 100 base units
+    assert_eq!(
+        auction_fill(1, 500 * USD, 500 * USD, price).unwrap(),
+        AuctionFill {
+            amount_burned: 100,
+            lamports_paid: 1,
+        }
+    );
+    assert_eq!(
+        auction_fill(0, 50 * USD, 50 * USD, price).unwrap(),
+        AuctionFill {
+            amount_burned: 0,
+            lamports_paid: 0,
+        }
+    );
+}
+
+#[test]
+fn auction_parameters_examples() {
+    let linear = AuctionCurve::Linear { duration: 60 };
+    assert!(validate_auction_parameters(1_000, 5_000, linear).is_ok());
+    assert!(validate_auction_parameters(1_000, 11_000, linear).is_ok());
+    let invalid = [
+        (1_000, 11_001, linear),
+        (1_000, 0, linear),
+        (1_000, 5_000, AuctionCurve::Linear { duration: 0 }),
+        (
+            1_000,
+            5_000,
+            AuctionCurve::Exponential {
+                step: 0,
+                cut_bps: 100,
+            },
+        ),
+        (
+            1_000,
+            5_000,
+            AuctionCurve::Exponential {
+                step: 60,
+                cut_bps: 0,
+            },
+        ),
+        (
+            1_000,
+            5_000,
+            AuctionCurve::Exponential {
+                step: 60,
+                cut_bps: 10_001,
+            },
+        ),
+    ];
+    for (premium_bps, floor_bps, curve) in invalid {
+        assert!(is_error(
+            validate_auction_parameters(premium_bps, floor_bps, curve),
+            StablecointError::InvalidAuctionParameters
+        ));
+    }
+}
+
+// ============================================================================
+// Properties
+// ============================================================================
+
+// $0.01 to $10,000 per SOL with 8 decimals
+fn realistic_price() -> impl Strategy<Value = i64> {
+    1_000_000i64..1_000_000_000_000
+}
+
+// up to 100,000 SOL, so doubled balances at doubled prices still fit in a u64 of USD
+fn realistic_lamports() -> impl Strategy<Value = u64> {
+    0u64..100_000 * LAMPORTS_PER_SOL
+}
+
+// `None` when even the u128 product overflows
+fn exact_usd_value(lamports: u64, price: i64) -> Option<u128> {
+    (lamports as u128)
+        .checked_mul(price as u128)
+        .map(|value| value / PRICE_SCALE)
+}
+
+fn exact_lamports(amount_in_usd: u64, price: i64) -> u128 {
+    amount_in_usd as u128 * PRICE_SCALE / price as u128
+}
+
+proptest! {
+    #[test]
+    fn usd_value_is_exact_or_fails(lamports in any::<u64>(), price in 1..=i64::MAX) {
+        let exact = exact_usd_value(lamports, price);
+        match usd_value(lamports, sol_usd(price)) {
+            Ok(value) => prop_assert_eq!(Some(value as u128), exact),
+            Err(_) => prop_assert!(exact.is_none_or(|exact| exact > u64::MAX as u128)),
+        }
+    }
+
+    #[test]
+    fn lamports_from_usd_is_exact_or_fails(amount in any::<u64>(), price in 1..=i64::MAX) {
+        let exact = exact_lamports(amount, price);
+        match lamports_from_usd(amount, sol_usd(price)) {
+            Ok(lamports) => prop_assert_eq!(lamports as u128, exact),
+            Err(_) => prop_assert!(exact > u64::MAX as u128),
+        }
+    }
+
+    #[test]
+    fn non_positive_price_is_rejected(amount in any::<u64>(), price in i64::MIN..=0) {
+        prop_assert!(is_error(usd_value(amount, sol_usd(price)), StablecointError::InvalidPrice));
+        prop_assert!(is_error(
+            lamports_from_usd(amount, sol_usd(price)),
+            StablecointError::InvalidPrice
+        ));
+        prop_assert!(is_error(
+            health_factor(amount, 1, LIQUIDATION_THRESHOLD, sol_usd(price)),
+            StablecointError::InvalidPrice
+        ));
+    }
+
+    #[test]
+    fn exponent_does_not_change_the_value(
+        lamports in realistic_lamports(),
+        amount in 0u64..10_000_000_000_000_000,
+        price in realistic_price(),
+        extra_decimals in 0u32..=6,
+    ) {
+        let scaled = OraclePrice::new(price * 10i64.pow(extra_decimals), -8 - extra_decimals as i32);
+        prop_assert_eq!(usd_value(lamports, scaled).unwrap(), usd_value(lamports, sol_usd(price)).unwrap());
+        prop_assert_eq!(
+            lamports_from_usd(amount, scaled).unwrap(),
+            lamports_from_usd(amount, sol_usd(price)).unwrap()
+        );
+    }
+
+    #[test]
+    fn lamports_round_trip_never_gains(lamports in realistic_lamports(), price in realistic_price()) {
+        let value = usd_value(lamports, sol_usd(price)).unwrap();
+        let back = lamports_from_usd(value, sol_usd(price)).unwrap();
+        prop_assert!(back <= lamports);
+        // each conversion loses less than one unit of its output
+        let lamports_per_usd_unit = (PRICE_SCALE / price as u128) as u64;
+        prop_assert!(lamports - back <= lamports_per_usd_unit + 1);
+    }
+
+    #[test]
+    fn usd_round_trip_never_gains(amount in 0u64..10_000_000_000_000_000, price in realistic_price()) {
+        let lamports = lamports_from_usd(amount, sol_usd(price)).unwrap();
+        let back = usd_value(lamports, sol_usd(price)).unwrap();
+        prop_assert!(back <= amount);
+        let usd_units_per_lamport = (price as u128 / PRICE_SCALE) as u64;
+        prop_assert!(amount - back <= usd_units_per_lamport + 1);
+    }
+
+    #[test]
+    fn usd_value_is_monotonic(
+        lamports in realistic_lamports(),
+        extra_lamports in realistic_lamports(),
+        price in realistic_price(),
+        price_increase in 0i64..1_000_000_000_000,
+    ) {
+        let value = usd_value(lamports, sol_usd(price)).unwrap();
+        prop_assert!(usd_value(lamports + extra_lamports, sol_usd(price)).unwrap() >= value);
+        prop_assert!(usd_value(lamports, sol_usd(price + price_increase)).unwrap() >= value);
+        let amount = lamports_from_usd(value, sol_usd(price)).unwrap();
+        prop_assert!(lamports_from_usd(value, sol_usd(price + price_increase)).unwrap() <= amount);
+    }
+
+    #[test]
+    fn health_factor_is_monotonic(
+        lamports in realistic_lamports(),
+        extra_lamports in realistic_lamports(),
+        minted in 1u64..u64::MAX / 2,
+        extra_minted in 0u64..u64::MAX / 2,
+        price in realistic_price(),
+        price_increase in 0i64..1_000_000_000_000,
+        threshold in 1u64..=100,
+    ) {
+        let price_after = sol_usd(price + price_increase);
+        let price = sol_usd(price);
+        let health = health_factor(lamports, minted, threshold, price).unwrap();
+        prop_assert!(health_factor(lamports + extra_lamports, minted, threshold, price).unwrap() >= health);
+        prop_assert!(health_factor(lamports, minted, threshold, price_after).unwrap() >= health);
+        prop_assert!(health_factor(lamports, minted + extra_minted, threshold, price).unwrap() <= health);
+    }
+
+    #[test]
+    fn health_factor_never_overstates(
+        lamports in realistic_lamports(),
+        minted in 1u64..u64::MAX,
+        price in realistic_price(),
+        threshold in 1u64..=100,
+    ) {
+        let health = health_factor(lamports, minted, threshold, sol_usd(price)).unwrap();
+        let exact_adjusted = lamports as u128 * price as u128 * threshold as u128;
+        prop_assert!(health as u128 * minted as u128 * PRICE_SCALE * 100 <= exact_adjusted);
+    }
+
+    #[test]
+    fn health_factor_without_debt_is_max(lamports in any::<u64>(), price in realistic_price()) {
+        if let Ok(health) = health_factor(lamports, 0, LIQUIDATION_THRESHOLD, sol_usd(price)) {
+            prop_assert_eq!(health, u64::MAX);
+        }
+    }
+
+    #[test]
+    fn liquidation_never_pays_more_than_the_position_holds(
+        lamport_balance in any::<u64>(),
+        amount_minted in any::<u64>(),
+        amount_to_burn in any::<u64>(),
+        bonus in 0u64..=100,
+        price in 1..=i64::MAX,
+    ) {
+        let lamports = exact_lamports(amount_to_burn, price);
+        let paid = lamports + lamports * bonus as u128 / 100;
+        match liquidate_position(lamport_balance, amount_minted, amount_to_burn, bonus, sol_usd(price)) {
+            Ok(outcome) => {
+                prop_assert!(outcome.lamports_paid <= lamport_balance);
+                prop_assert_eq!(outcome.lamport_balance + outcome.lamports_paid, lamport_balance);
+                prop_assert_eq!(outcome.amount_minted + amount_to_burn, amount_minted);
+                // the payout is the burned value plus the bonus, both rounded down
+                prop_assert_eq!(outcome.lamports_paid as u128, paid);
+            }
+            Err(_) => prop_assert!(paid > lamport_balance as u128 || amount_to_burn > amount_minted),
+        }
+    }
+
+    #[test]
+    fn underwater_positions_cannot_be_fully_liquidated(
+        lamport_balance in LAMPORTS_PER_SOL / 1_000..1_000 * LAMPORTS_PER_SOL,
+        price in realistic_price(),
+    ) {
+        // debt worth more than the collateral can't be repaid with the bonus on top
+        let debt = usd_value(lamport_balance, sol_usd(price)).unwrap() + 1;
+        let result = liquidate_position(lamport_balance, debt, debt, LIQUIDATION_BONUS, sol_usd(price));
+        prop_assert!(is_error(result, StablecointError::InsufficientCollateral));
+    }
+
+    #[test]
+    fn max_debt_is_the_largest_healthy_debt(
+        lamports in realistic_lamports(),
+        price in realistic_price(),
+        threshold in 1u64..=100,
+        min_health_factor in 1u64..5,
+    ) {
+        let debt = max_debt(lamports, threshold, min_health_factor, sol_usd(price)).unwrap();
+        if debt > 0 {
+            prop_assert!(health_factor(lamports, debt, threshold, sol_usd(price)).unwrap() >= min_health_factor);
+        }
+        prop_assert!(health_factor(lamports, debt + 1, threshold, sol_usd(price)).unwrap() < min_health_factor);
+    }
+
+    #[test]
+    fn max_withdrawable_is_the_largest_healthy_withdrawal(
+        lamports in realistic_lamports(),
+        debt in 1u64..1_000_000 * USD,
+        price in realistic_price(),
+        threshold in 1u64..=100,
+        min_health_factor in 1u64..5,
+    ) {
+        let withdrawable =
+            max_withdrawable(lamports, debt, threshold, min_health_factor, sol_usd(price)).unwrap();
+        let remaining = lamports - withdrawable;
+        let healthy = |balance| {
+            health_factor(balance, debt, threshold, sol_usd(price)).unwrap() >= min_health_factor
+        };
+        if withdrawable > 0 {
+            prop_assert!(healthy(remaining));
+        }
+        // not even one more lamport can leave
+        prop_assert!(remaining == 0 || !healthy(remaining - 1));
+    }
+
+    #[test]
+    fn max_safe_modes_stay_healthy(
+        lamports in realistic_lamports(),
+        debt in 0u64..1_000_000 * USD,
+        buffer_bps in 0u16..=10_000,
+        price in realistic_price(),
+    ) {
+        let price = sol_usd(price);
+        let mode = AmountMode::MaxSafe { buffer_bps };
+        let (threshold, min_health_factor) = (LIQUIDATION_THRESHOLD, MIN_HEALTH_FACTOR);
+        let minted = mint_amount(mode, lamports, 0, threshold, min_health_factor, price).unwrap();
+        let health = health_factor(lamports, minted, threshold, price).unwrap();
+        prop_assert!(!is_liquidatable(health, min_health_factor));
+
+        let withdrawn =
+            withdraw_amount(mode, lamports, debt, threshold, min_health_factor, price).unwrap();
+        let withdrawable =
+            max_withdrawable(lamports, debt, threshold, min_health_factor, price).unwrap();
+        prop_assert!(withdrawn <= withdrawable);
+    }
+
+    #[test]
+    fn liquidation_price_separates_healthy_prices(
+        lamports in 1u64..100_000 * LAMPORTS_PER_SOL,
+        debt in 1u64..1_000_000 * USD,
+        threshold in 1u64..=100,
+        min_health_factor in 1u64..5,
+    ) {
+        let price = liquidation_price(lamports, debt, threshold, min_health_factor, -8).unwrap();
+        let health_at = |price| health_factor(lamports, debt, threshold, sol_usd(price)).ok();
+        prop_assume!(price < i64::MAX);
+        // prices so high the collateral value overflows a u64 are skipped
+        if let Some(health) = health_at(price) {
+            prop_assert!(health >= min_health_factor);
+        }
+        if let Some(health) = health_at(price - 1).filter(|_| price > 1) {
+            prop_assert!(is_liquidatable(health, min_health_factor));
+        }
+    }
+
+    #[test]
+    fn auction_price_falls_between_start_and_floor(
+        start in 2i64..1_000_000_000_000,
+        floor_fraction in 1u64..=10_000,
+        elapsed in 0i64..1_000_000,
+        later in 0i64..1_000_000,
+        linear in any::<bool>(),
+        duration in 1i64..100_000,
+        cut_bps in 1u64..=10_000,
+    ) {
+        let floor = ((start as u128 * floor_fraction as u128 / 10_000) as i64).max(1);
+        let curve = if linear {
+            AuctionCurve::Linear { duration }
+        } else {
+            AuctionCurve::Exponential { step: duration, cut_bps }
+        };
+        let price = auction_price(start, floor, elapsed, curve).unwrap();
+        prop_assert!(floor <= price && price <= start);
+        prop_assert!(auction_price(start, floor, elapsed + later, curve).unwrap() <= price);
+    }
+
+    #[test]
+    fn auction_fill_never_overpays_the_bidder(
+        lamports in realistic_lamports(),
+        debt in 0u64..1_000_000 * USD,
+        amount in 0u64..2_000_000 * USD,
+        price in realistic_price(),
+    ) {
+        let fill = auction_fill(lamports, debt, amount, sol_usd(price)).unwrap();
+        prop_assert!(fill.lamports_paid <= lamports);
+        prop_assert!(fill.amount_burned <= debt.min(amount));
+        // what the bidder gets is worth no more than what it burned
+        prop_assert!(usd_value(fill.lamports_paid, sol_usd(price)).unwrap() <= fill.amount_burned);
+    }
+}
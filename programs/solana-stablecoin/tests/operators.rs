@@ -0,0 +1,101 @@
+mod common;
+
+use common::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+use solana_stablecoin::{
+    error::StablecointError, PERMISSION_ADD_COLLATERAL, PERMISSION_MINT, PERMISSION_REDEEM,
+    PERMISSION_REPAY,
+};
+use solana_stablecoin_client::{self as client, PositionAddresses};
+
+/// An owner's position with 10 SOL and $100 minted, and an operator holding $100 of its
+/// own that may do everything on it up to a $100 cap.
+fn operator_setup(context: &mut TestContext) -> (Keypair, PositionAddresses, Keypair) {
+    let owner = context.user(sol(20));
+    let position = context.open_and_mint(&owner, 0, sol(10), usd(100)).unwrap();
+    let operator = context.user(sol(100));
+    context
+        .open_and_mint(&operator, 0, sol(50), usd(100))
+        .unwrap();
+    let permissions =
+        PERMISSION_ADD_COLLATERAL | PERMISSION_REPAY | PERMISSION_MINT | PERMISSION_REDEEM;
+    let instruction = client::approve_operator(
+        &owner.pubkey(),
+        &position,
+        &operator.pubkey(),
+        permissions,
+        usd(100),
+    );
+    context.send(&[instruction], &owner).unwrap();
+    (owner, position, operator)
+}
+
+fn operator_mint(
+    context: &mut TestContext,
+    operator: &Keypair,
+    owner: &Keypair,
+    position: &PositionAddresses,
+    amount_to_mint: u64,
+) -> Result<(), TransactionError> {
+    let instruction = client::operator_deposit_collateral_and_mint_tokens(
+        &operator.pubkey(),
+        &owner.pubkey(),
+        position,
+        &context.price_update,
+        &TOKEN_PROGRAM,
+        sol(1),
+        amount_to_mint,
+    );
+    context.send(&[instruction], operator)
+}
+
+fn operator_redeem(
+    context: &mut TestContext,
+    operator: &Keypair,
+    owner: &Keypair,
+    position: &PositionAddresses,
+    amount_to_burn: u64,
+) -> Result<(), TransactionError> {
+    let instruction = client::operator_redeem_collateral_and_burn_tokens(
+        &operator.pubkey(),
+        &owner.pubkey(),
+        position,
+        &context.price_update,
+        &TOKEN_PROGRAM,
+        sol(1),
+        amount_to_burn,
+    );
+    context.send(&[instruction], operator)
+}
+
+#[test]
+fn operator_mints_to_the_owner() {
+    let mut context = TestContext::new(100);
+    let (owner, position, operator) = operator_setup(&mut context);
+    operator_mint(&mut context, &operator, &owner, &position, usd(10)).unwrap();
+    assert_eq!(context.stablecoin_balance(&owner.pubkey()), usd(110));
+    assert_eq!(context.collateral(&position).lamport_balance, sol(11));
+}
+
+#[test]
+fn blocklisted_operator_cannot_mint() {
+    let mut context = TestContext::new(100);
+    let (owner, position, operator) = operator_setup(&mut context);
+    context.blocklist(&operator.pubkey()).unwrap();
+    let result = operator_mint(&mut context, &operator, &owner, &position, usd(10));
+    assert_program_error(result, 0, StablecointError::Blocklisted);
+    assert_eq!(context.collateral(&position).amount_minted, usd(100));
+}
+
+#[test]
+fn blocklisted_operator_cannot_redeem() {
+    let mut context = TestContext::new(100);
+    let (owner, position, operator) = operator_setup(&mut context);
+    context.blocklist(&operator.pubkey()).unwrap();
+    let result = operator_redeem(&mut context, &operator, &owner, &position, usd(10));
+    assert_program_error(result, 0, StablecointError::Blocklisted);
+    assert_eq!(context.collateral(&position).lamport_balance, sol(10));
+}
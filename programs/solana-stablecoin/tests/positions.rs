@@ -0,0 +1,289 @@
+mod common;
+
+use common::*;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+use solana_stablecoin::{error::StablecointError, MAXIMUM_AGE, MIN_HEALTH_FACTOR};
+use solana_stablecoin_client::{self as client, PositionAddresses};
+
+#[test]
+fn initialize_config_sets_defaults() {
+    let context = TestContext::new(100);
+    let config = context.config();
+    assert_eq!(config.authority, context.authority.pubkey());
+    assert_eq!(config.token_program, TOKEN_PROGRAM);
+    assert_eq!(
+        config.recovery_authority,
+        context.recovery_authority.pubkey()
+    );
+    assert_eq!(config.min_health_factor, MIN_HEALTH_FACTOR);
+}
+
+#[test]
+fn initialize_config_twice_fails() {
+    let mut context = TestContext::new(100);
+    let authority = context.authority.insecure_clone();
+    let result = context.send(
+        &[client::initialize_config(
+            &authority.pubkey(),
+            "Again".to_string(),
+            "AGN".to_string(),
+            String::new(),
+            context.recovery_authority.pubkey(),
+        )],
+        &authority,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn deposit_and_mint() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    // 1 SOL at $100 backs at most $50 with a 50% liquidation threshold
+    let position = context.open_and_mint(&user, 0, sol(1), usd(50)).unwrap();
+
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.creator, user.pubkey());
+    assert_eq!(collateral.lamport_balance, sol(1));
+    assert_eq!(collateral.amount_minted, usd(50));
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        sol(1) + context.vault_rent()
+    );
+    assert_eq!(context.stablecoin_balance(&user.pubkey()), usd(50));
+}
+
+#[test]
+fn mint_below_minimum_health_factor_fails() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let result = context.open_and_mint(&user, 0, sol(1), usd(51));
+    assert_program_error(
+        result.map(|_| ()),
+        1,
+        StablecointError::BelowMinimumHealthFactor,
+    );
+}
+
+#[test]
+fn mint_with_stale_price_fails() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    context.warp_to(START_TIMESTAMP + MAXIMUM_AGE as i64 + 1);
+    assert!(context.open_and_mint(&user, 0, sol(1), usd(10)).is_err());
+
+    context.set_price(usd_price(100), START_TIMESTAMP + MAXIMUM_AGE as i64 + 1);
+    assert!(context.open_and_mint(&user, 0, sol(1), usd(10)).is_ok());
+}
+
+#[test]
+fn mint_with_wrong_feed_fails() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let mut message = price_message(usd_price(100), START_TIMESTAMP);
+    message.feed_id = [1; 32];
+    context.write_price_update(message);
+    assert!(context.open_and_mint(&user, 0, sol(1), usd(10)).is_err());
+}
+
+#[test]
+fn mint_with_negative_price_fails() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    context.set_price(-usd_price(100), START_TIMESTAMP);
+    let result = context.open_and_mint(&user, 0, sol(1), usd(10));
+    assert_program_error(result.map(|_| ()), 1, StablecointError::InvalidPrice);
+}
+
+#[test]
+fn redeem_and_burn() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), usd(50)).unwrap();
+    let price_update = context.price_update;
+    context
+        .send(
+            &[client::redeem_collateral_and_burn_tokens(
+                &user.pubkey(),
+                &position,
+                &price_update,
+                &TOKEN_PROGRAM,
+                (sol(1) / 2).into(),
+                usd(25),
+            )],
+            &user,
+        )
+        .unwrap();
+
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.lamport_balance, sol(1) / 2);
+    assert_eq!(collateral.amount_minted, usd(25));
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        sol(1) / 2 + context.vault_rent()
+    );
+    assert_eq!(context.stablecoin_balance(&user.pubkey()), usd(25));
+}
+
+#[test]
+fn redeem_below_minimum_health_factor_fails() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), usd(50)).unwrap();
+    let price_update = context.price_update;
+
+    let result = context.send(
+        &[client::redeem_collateral_and_burn_tokens(
+            &user.pubkey(),
+            &position,
+            &price_update,
+            &TOKEN_PROGRAM,
+            (sol(1) / 2).into(),
+            0,
+        )],
+        &user,
+    );
+    assert_program_error(result, 0, StablecointError::BelowMinimumHealthFactor);
+}
+
+#[test]
+fn burn_more_than_minted_fails() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), usd(10)).unwrap();
+    let price_update = context.price_update;
+
+    let result = context.send(
+        &[client::redeem_collateral_and_burn_tokens(
+            &user.pubkey(),
+            &position,
+            &price_update,
+            &TOKEN_PROGRAM,
+            0u64.into(),
+            usd(11),
+        )],
+        &user,
+    );
+    assert_program_error(result, 0, StablecointError::MathError);
+}
+
+#[test]
+fn update_config_changes_minimum_health_factor() {
+    let mut context = TestContext::new(100);
+    let authority = context.authority.insecure_clone();
+    context
+        .send(&[client::update_config(&authority.pubkey(), 2)], &authority)
+        .unwrap();
+    assert_eq!(context.config().min_health_factor, 2);
+
+    // a position at health factor 1 can no longer be opened
+    let user = context.user(sol(10));
+    let result = context.open_and_mint(&user, 0, sol(1), usd(50));
+    assert_program_error(
+        result.map(|_| ()),
+        1,
+        StablecointError::BelowMinimumHealthFactor,
+    );
+    assert!(context.open_and_mint(&user, 0, sol(1), usd(25)).is_ok());
+}
+
+#[test]
+fn update_config_requires_the_authority() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(1));
+    let result = context.send(&[client::update_config(&user.pubkey(), 2)], &user);
+    assert!(result.is_err());
+    assert_eq!(context.config().min_health_factor, MIN_HEALTH_FACTOR);
+}
+
+fn deposit_collateral(
+    context: &mut TestContext,
+    depositor: &Keypair,
+    position: &PositionAddresses,
+) -> Result<(), TransactionError> {
+    let instruction = client::deposit_collateral_and_mint_tokens(
+        &depositor.pubkey(),
+        position,
+        &context.price_update,
+        &TOKEN_PROGRAM,
+        sol(1),
+        0u64.into(),
+    );
+    context.send(&[instruction], depositor)
+}
+
+#[test]
+fn accepted_transfer_moves_the_position() {
+    let mut context = TestContext::new(100);
+    let owner = context.user(sol(10));
+    let buyer = context.user(sol(10));
+    let position = context.open_and_mint(&owner, 0, sol(1), usd(10)).unwrap();
+
+    let propose = client::propose_position_transfer(&owner.pubkey(), &position, buyer.pubkey());
+    context.send(&[propose], &owner).unwrap();
+    // proposing alone leaves the NFT where it is
+    assert_eq!(context.position_nft_balance(&position, &owner.pubkey()), 1);
+
+    let accept = client::accept_position_transfer(&buyer.pubkey(), &owner.pubkey(), &position);
+    context.send(&[accept], &buyer).unwrap();
+    assert_eq!(context.position_nft_balance(&position, &owner.pubkey()), 0);
+    assert_eq!(context.position_nft_balance(&position, &buyer.pubkey()), 1);
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.depositor, buyer.pubkey());
+    assert_eq!(collateral.pending_depositor, Pubkey::default());
+    assert_eq!(collateral.amount_minted, usd(10));
+
+    let result = deposit_collateral(&mut context, &owner, &position);
+    assert_program_error(result, 0, StablecointError::NotPositionHolder);
+    deposit_collateral(&mut context, &buyer, &position).unwrap();
+}
+
+#[test]
+fn only_the_pending_owner_can_accept_a_transfer() {
+    let mut context = TestContext::new(100);
+    let owner = context.user(sol(10));
+    let buyer = context.user(sol(10));
+    let outsider = context.user(sol(10));
+    let position = context.open_and_mint(&owner, 0, sol(1), 0u64).unwrap();
+    let propose = client::propose_position_transfer(&owner.pubkey(), &position, buyer.pubkey());
+    context.send(&[propose], &owner).unwrap();
+
+    let accept = client::accept_position_transfer(&outsider.pubkey(), &owner.pubkey(), &position);
+    let result = context.send(&[accept], &outsider);
+    assert_program_error(result, 0, StablecointError::NotPendingDepositor);
+
+    // cancelling drops the buyer as well
+    let cancel = client::propose_position_transfer(&owner.pubkey(), &position, Pubkey::default());
+    context.send(&[cancel], &owner).unwrap();
+    let accept = client::accept_position_transfer(&buyer.pubkey(), &owner.pubkey(), &position);
+    let result = context.send(&[accept], &buyer);
+    assert_program_error(result, 0, StablecointError::NotPendingDepositor);
+    assert_eq!(context.position_nft_balance(&position, &owner.pubkey()), 1);
+}
+
+#[test]
+fn close_position_closes_the_nft_token_account() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), 0u64).unwrap();
+    let redeem = client::redeem_collateral_and_burn_tokens(
+        &user.pubkey(),
+        &position,
+        &context.price_update,
+        &TOKEN_PROGRAM,
+        sol(1).into(),
+        0,
+    );
+    context.send(&[redeem], &user).unwrap();
+
+    let instruction = client::close_position(&user.pubkey(), &position, &TOKEN_PROGRAM);
+    context.send(&[instruction], &user).unwrap();
+    assert_eq!(
+        context.lamports(&position.position_token_account(&user.pubkey())),
+        0
+    );
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::*;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use solana_stablecoin::{error::StablecointError, RECOVERY_TIMELOCK};
+use solana_stablecoin_client as client;
+
+#[test]
+fn initialize_config_requires_a_separate_recovery_authority() {
+    let mut context = TestContext::uninitialized(100);
+    let authority = context.authority.insecure_clone();
+    let result = context.send(
+        &[client::initialize_config(
+            &authority.pubkey(),
+            "Programmable Money".to_string(),
+            "USDS".to_string(),
+            String::new(),
+            authority.pubkey(),
+        )],
+        &authority,
+    );
+    assert_program_error(result, 0, StablecointError::InvalidRecoveryAuthority);
+}
+
+#[test]
+fn set_recovery_authority_below_the_minimum_timelock_fails() {
+    let mut context = TestContext::new(100);
+    let authority = context.authority.insecure_clone();
+    let recovery_authority = Pubkey::new_unique();
+    for recovery_timelock in [0, RECOVERY_TIMELOCK - 1] {
+        let instruction = client::set_recovery_authority(
+            &authority.pubkey(),
+            recovery_authority,
+            recovery_timelock,
+        );
+        let result = context.send(&[instruction], &authority);
+        assert_program_error(result, 0, StablecointError::InvalidRecoveryTimelock);
+    }
+    assert_eq!(context.config().recovery_timelock, RECOVERY_TIMELOCK);
+}
+
+#[test]
+fn set_recovery_authority_at_the_minimum_timelock() {
+    let mut context = TestContext::new(100);
+    let authority = context.authority.insecure_clone();
+    let recovery_authority = Pubkey::new_unique();
+    let instruction =
+        client::set_recovery_authority(&authority.pubkey(), recovery_authority, RECOVERY_TIMELOCK);
+    context.send(&[instruction], &authority).unwrap();
+    assert_eq!(context.config().recovery_authority, recovery_authority);
+
+    // handing the role back to the admin key is rejected
+    let instruction =
+        client::set_recovery_authority(&authority.pubkey(), authority.pubkey(), RECOVERY_TIMELOCK);
+    let result = context.send(&[instruction], &authority);
+    assert_program_error(result, 0, StablecointError::InvalidRecoveryAuthority);
+}
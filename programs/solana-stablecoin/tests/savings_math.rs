@@ -0,0 +1,121 @@
+//! Unit and property tests of the savings share and accrual math.
+use proptest::prelude::*;
+use solana_stablecoin::{
+    accrue_savings, amount_to_shares, shares_to_amount, state::SavingsVault,
+    SAVINGS_EXCHANGE_RATE_PRECISION, SECONDS_PER_YEAR,
+};
+
+const START: i64 = 1_700_000_000;
+const YEAR: i64 = SECONDS_PER_YEAR as i64;
+
+/// A vault paying `savings_rate_bps` a year on `total_shares` at an exchange rate of 1.
+fn vault(total_shares: u64, savings_rate_bps: u64, mint_budget: u64) -> SavingsVault {
+    SavingsVault {
+        token_account: Default::default(),
+        savings_rate_bps,
+        mint_budget,
+        total_dripped: 0,
+        total_shares,
+        exchange_rate: SAVINGS_EXCHANGE_RATE_PRECISION,
+        last_update_timestamp: START,
+        bump: 0,
+        bump_token_account: 0,
+    }
+}
+
+#[test]
+fn conversions_round_down() {
+    // one share is worth 1.5
+    let exchange_rate = SAVINGS_EXCHANGE_RATE_PRECISION * 3 / 2;
+    assert_eq!(amount_to_shares(10, exchange_rate).unwrap(), 6);
+    assert_eq!(shares_to_amount(7, exchange_rate).unwrap(), 10);
+    // too small to buy a single share
+    assert_eq!(amount_to_shares(1, exchange_rate).unwrap(), 0);
+}
+
+#[test]
+fn first_deposit_into_an_empty_vault_is_one_to_one() {
+    let mut savings_vault = vault(0, 500, 1_000_000);
+    // nobody holds shares, so a year of accrual mints nothing and keeps the rate at 1
+    assert_eq!(accrue_savings(&mut savings_vault, START + YEAR).unwrap(), 0);
+    assert_eq!(savings_vault.exchange_rate, SAVINGS_EXCHANGE_RATE_PRECISION);
+    assert_eq!(savings_vault.last_update_timestamp, START + YEAR);
+    assert_eq!(
+        amount_to_shares(1_000, savings_vault.exchange_rate).unwrap(),
+        1_000
+    );
+}
+
+#[test]
+fn accrual_is_simple_interest_since_the_last_update() {
+    let mut savings_vault = vault(1_000_000, 500, u64::MAX);
+    assert_eq!(
+        accrue_savings(&mut savings_vault, START + YEAR).unwrap(),
+        50_000
+    );
+    assert_eq!(
+        savings_vault.exchange_rate,
+        SAVINGS_EXCHANGE_RATE_PRECISION * 105 / 100
+    );
+    assert_eq!(savings_vault.total_dripped, 50_000);
+    assert_eq!(
+        shares_to_amount(1_000_000, savings_vault.exchange_rate).unwrap(),
+        1_050_000
+    );
+
+    // a clock that hasn't moved forward accrues nothing
+    assert_eq!(accrue_savings(&mut savings_vault, START).unwrap(), 0);
+    assert_eq!(savings_vault.last_update_timestamp, START + YEAR);
+}
+
+#[test]
+fn accrual_stops_at_the_mint_budget() {
+    let mut savings_vault = vault(1_000_000, 500, 20_000);
+    assert_eq!(
+        accrue_savings(&mut savings_vault, START + YEAR).unwrap(),
+        20_000
+    );
+    assert_eq!(savings_vault.total_dripped, 20_000);
+    // the rate only grows by what the budget backs
+    assert_eq!(
+        savings_vault.exchange_rate,
+        SAVINGS_EXCHANGE_RATE_PRECISION * 102 / 100
+    );
+
+    assert_eq!(
+        accrue_savings(&mut savings_vault, START + 2 * YEAR).unwrap(),
+        0
+    );
+    assert_eq!(
+        savings_vault.exchange_rate,
+        SAVINGS_EXCHANGE_RATE_PRECISION * 102 / 100
+    );
+}
+
+proptest! {
+    #[test]
+    fn deposit_and_withdraw_never_gain(
+        amount in 0u64..u64::MAX / 4,
+        rate_increase in 0u128..SAVINGS_EXCHANGE_RATE_PRECISION,
+    ) {
+        let exchange_rate = SAVINGS_EXCHANGE_RATE_PRECISION + rate_increase;
+        let shares = amount_to_shares(amount, exchange_rate).unwrap();
+        prop_assert!(shares_to_amount(shares, exchange_rate).unwrap() <= amount);
+    }
+
+    #[test]
+    fn drips_never_exceed_the_budget(
+        total_shares in 1u64..1_000_000_000_000_000,
+        savings_rate_bps in 0u64..10_000,
+        mint_budget in 0u64..1_000_000_000_000,
+        elapsed in 0i64..10 * YEAR,
+    ) {
+        let mut savings_vault = vault(total_shares, savings_rate_bps, mint_budget);
+        let dripped = accrue_savings(&mut savings_vault, START + elapsed).unwrap();
+        prop_assert!(dripped <= mint_budget);
+        prop_assert_eq!(savings_vault.total_dripped, dripped);
+        // the minted amount covers what the shares gained
+        let value = shares_to_amount(total_shares, savings_vault.exchange_rate).unwrap();
+        prop_assert!(value <= total_shares + dripped);
+    }
+}
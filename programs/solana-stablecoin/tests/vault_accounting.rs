@@ -0,0 +1,139 @@
+mod common;
+
+use common::*;
+use solana_sdk::{account::Account, signature::Signer, system_program};
+use solana_stablecoin::AmountMode;
+use solana_stablecoin_client as client;
+
+#[test]
+fn first_deposit_funds_the_vault_rent() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    // far below the rent-exempt minimum on its own
+    let position = context.open_and_mint(&user, 0, 1_000, 0u64).unwrap();
+    assert_eq!(context.collateral(&position).lamport_balance, 1_000);
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        1_000 + context.vault_rent()
+    );
+
+    // later deposits only add collateral
+    context.deposit(&user, &position, sol(1), 0u64).unwrap();
+    assert_eq!(
+        context.collateral(&position).lamport_balance,
+        sol(1) + 1_000
+    );
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        sol(1) + 1_000 + context.vault_rent()
+    );
+}
+
+#[test]
+fn donations_are_not_collateral() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let donor = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), 0u64).unwrap();
+
+    context
+        .donate(&donor, &position.sol_account, sol(5))
+        .unwrap();
+    assert_eq!(context.collateral(&position).lamport_balance, sol(1));
+
+    context
+        .deposit(&user, &position, sol(1), AmountMode::All)
+        .unwrap();
+    let collateral = context.collateral(&position);
+    assert_eq!(collateral.lamport_balance, sol(2));
+    // 2 SOL at $100 back $100, the donated 5 SOL don't count
+    assert_eq!(collateral.amount_minted, usd(100));
+}
+
+#[test]
+fn sweep_donations_leaves_collateral_and_rent() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let donor = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), usd(10)).unwrap();
+    context
+        .donate(&donor, &position.sol_account, sol(2))
+        .unwrap();
+
+    let authority = context.authority.pubkey();
+    let authority_before = context.lamports(&authority);
+    context.sweep_donations(&position).unwrap();
+    // minus the 5000 lamport transaction fee
+    assert_eq!(
+        context.lamports(&authority),
+        authority_before + sol(2) - 5_000
+    );
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        sol(1) + context.vault_rent()
+    );
+    assert_eq!(context.collateral(&position).lamport_balance, sol(1));
+
+    // nothing left to sweep
+    context.sweep_donations(&position).unwrap();
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        sol(1) + context.vault_rent()
+    );
+}
+
+#[test]
+fn sweep_donations_requires_the_authority() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), 0u64).unwrap();
+    context
+        .donate(&user, &position.sol_account, sol(1))
+        .unwrap();
+
+    let instruction = client::sweep_donations(&user.pubkey(), &position);
+    assert!(context.send(&[instruction], &user).is_err());
+}
+
+#[test]
+fn close_position_returns_the_rent_reserve() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), 0u64).unwrap();
+    context
+        .redeem(&user, &position, AmountMode::All, 0)
+        .unwrap();
+    assert_eq!(
+        context.lamports(&position.sol_account),
+        context.vault_rent()
+    );
+
+    let instruction = client::close_position(&user.pubkey(), &position, &TOKEN_PROGRAM);
+    context.send(&[instruction], &user).unwrap();
+    assert_eq!(context.lamports(&position.sol_account), 0);
+}
+
+#[test]
+fn full_withdrawal_takes_dust_from_vaults_without_a_reserve() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), 0u64).unwrap();
+    // a vault funded before the rent reserve, with a stray transfer on top
+    let vault = Account {
+        lamports: sol(1) + 1_000,
+        data: Vec::new(),
+        owner: system_program::ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    context
+        .svm
+        .set_account(position.sol_account, vault)
+        .unwrap();
+
+    context
+        .redeem(&user, &position, AmountMode::All, 0)
+        .unwrap();
+    assert_eq!(context.collateral(&position).lamport_balance, 0);
+    assert_eq!(context.lamports(&position.sol_account), 0);
+}
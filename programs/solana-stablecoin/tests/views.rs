@@ -0,0 +1,123 @@
+mod common;
+
+use common::*;
+use solana_stablecoin::{LiquidationPrice, PositionHealth};
+use solana_stablecoin_client as client;
+
+#[test]
+fn position_health_matches_position() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(2), usd(50)).unwrap();
+
+    let health: PositionHealth = context.view(client::get_position_health(
+        &position.collateral_account,
+        &context.price_update,
+    ));
+    assert_eq!(
+        health,
+        PositionHealth {
+            health_factor: 2,
+            collateral_value: usd(200),
+            amount_minted: usd(50),
+            liquidatable: false,
+        }
+    );
+
+    context.set_price(usd_price(40), START_TIMESTAMP);
+    let health: PositionHealth = context.view(client::get_position_health(
+        &position.collateral_account,
+        &context.price_update,
+    ));
+    assert_eq!(health.health_factor, 0);
+    assert!(health.liquidatable);
+}
+
+#[test]
+fn max_mintable_can_be_minted_and_no_more() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(2), usd(50)).unwrap();
+
+    let max_mintable: u64 = context.view(client::get_max_mintable(
+        &position.collateral_account,
+        &context.price_update,
+    ));
+    assert_eq!(max_mintable, usd(50));
+    assert!(context
+        .deposit(&user, &position, 0, max_mintable + 1)
+        .is_err());
+    context.deposit(&user, &position, 0, max_mintable).unwrap();
+}
+
+#[test]
+fn max_withdrawable_can_be_redeemed_and_no_more() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(2), usd(50)).unwrap();
+
+    let max_withdrawable: u64 = context.view(client::get_max_withdrawable(
+        &position.collateral_account,
+        &context.price_update,
+    ));
+    assert_eq!(max_withdrawable, sol(1));
+    assert!(context
+        .redeem(&user, &position, max_withdrawable + 1, 0)
+        .is_err());
+    context
+        .redeem(&user, &position, max_withdrawable, 0)
+        .unwrap();
+}
+
+#[test]
+fn liquidation_price_is_where_liquidation_starts() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(2), usd(50)).unwrap();
+    let liquidator = context.user(sol(100));
+    context
+        .open_and_mint(&liquidator, 0, sol(50), usd(100))
+        .unwrap();
+
+    let liquidation_price: LiquidationPrice = context.view(client::get_liquidation_price(
+        &position.collateral_account,
+        &context.price_update,
+    ));
+    // 2 SOL at 50% back $50 down to $50 per SOL
+    assert_eq!(
+        liquidation_price,
+        LiquidationPrice {
+            price: usd_price(50),
+            exponent: -8,
+        }
+    );
+
+    context.set_price(liquidation_price.price, START_TIMESTAMP);
+    assert!(context.liquidate(&liquidator, &position, usd(50)).is_err());
+    context.set_price(liquidation_price.price - 1, START_TIMESTAMP);
+    context.liquidate(&liquidator, &position, usd(50)).unwrap();
+}
+
+#[test]
+fn views_without_debt() {
+    let mut context = TestContext::new(100);
+    let user = context.user(sol(10));
+    let position = context.open_and_mint(&user, 0, sol(1), 0).unwrap();
+    let price_update = context.price_update;
+
+    let health: PositionHealth = context.view(client::get_position_health(
+        &position.collateral_account,
+        &price_update,
+    ));
+    assert_eq!(health.health_factor, u64::MAX);
+    let max_withdrawable: u64 = context.view(client::get_max_withdrawable(
+        &position.collateral_account,
+        &price_update,
+    ));
+    assert_eq!(max_withdrawable, sol(1));
+    let liquidation_price: LiquidationPrice = context.view(client::get_liquidation_price(
+        &position.collateral_account,
+        &price_update,
+    ));
+    assert_eq!(liquidation_price.price, 0);
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const SEED_HOOK_CONFIG: &[u8] = b"hook_config";
+pub const SEED_TRANSFER_ALLOWANCE: &[u8] = b"transfer_allowance";
+pub const SEED_EXTRA_ACCOUNT_METAS: &[u8] = b"extra-account-metas";
+pub const EXTRA_ACCOUNT_METAS_COUNT: usize = 5;
+// token account layout: the owner follows the 32 byte mint
+pub const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
+pub const PUBKEY_LENGTH: u8 = 32;
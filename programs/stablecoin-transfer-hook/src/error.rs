@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TransferHookError {
+    #[msg("Stablecoin transfers are paused")]
+    TransfersPaused,
+    #[msg("Source or destination owner is blocklisted")]
+    Blocklisted,
+    #[msg("Transfer limit for this epoch exceeded")]
+    EpochTransferLimitExceeded,
+    #[msg("Hook was not invoked by a token transfer")]
+    NotTransferring,
+    #[msg("Overflow, underflow or some other math error occured")]
+    MathError,
+}
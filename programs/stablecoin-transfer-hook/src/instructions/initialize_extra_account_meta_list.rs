@@ -0,0 +1,113 @@
+use crate::{
+    state::HookConfig, EXTRA_ACCOUNT_METAS_COUNT, PUBKEY_LENGTH, SEED_EXTRA_ACCOUNT_METAS,
+    SEED_HOOK_CONFIG, SEED_TRANSFER_ALLOWANCE, TOKEN_ACCOUNT_OWNER_OFFSET,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use solana_stablecoin::{SEED_BLOCKLIST, SEED_MINT_ACCOUNT};
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+// the execute instruction starts with source, mint, destination, owner and this list,
+// so the extra accounts below are indexed from 5
+const SOURCE_TOKEN_INDEX: u8 = 0;
+const DESTINATION_TOKEN_INDEX: u8 = 2;
+const STABLECOIN_PROGRAM_INDEX: u8 = 5;
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_HOOK_CONFIG],
+        bump = hook_config.bump,
+        has_one = authority,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+
+    #[account(
+        seeds = [SEED_MINT_ACCOUNT],
+        bump,
+        seeds::program = solana_stablecoin::ID,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: written below as a TLV extra account meta list
+    #[account(
+        init,
+        payer = authority,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_METAS_COUNT).unwrap(),
+        seeds = [SEED_EXTRA_ACCOUNT_METAS, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts Token-2022 appends to every stablecoin transfer, in the order `TransferHook` expects them.
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    let owner_of = |account_index: u8| Seed::AccountData {
+        account_index,
+        data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+        length: PUBKEY_LENGTH,
+    };
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(&solana_stablecoin::ID, false, false)?,
+        ExtraAccountMeta::new_with_seeds(
+            &[Seed::Literal {
+                bytes: SEED_HOOK_CONFIG.to_vec(),
+            }],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            STABLECOIN_PROGRAM_INDEX,
+            &[
+                Seed::Literal {
+                    bytes: SEED_BLOCKLIST.to_vec(),
+                },
+                owner_of(SOURCE_TOKEN_INDEX),
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            STABLECOIN_PROGRAM_INDEX,
+            &[
+                Seed::Literal {
+                    bytes: SEED_BLOCKLIST.to_vec(),
+                },
+                owner_of(DESTINATION_TOKEN_INDEX),
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: SEED_TRANSFER_ALLOWANCE.to_vec(),
+                },
+                owner_of(SOURCE_TOKEN_INDEX),
+            ],
+            false,
+            true,
+        )?,
+    ])
+}
+
+pub fn process_initialize_extra_account_meta_list(
+    context: Context<InitializeExtraAccountMetaList>,
+) -> Result<()> {
+    let extra_account_metas = extra_account_metas()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut context
+            .accounts
+            .extra_account_meta_list
+            .try_borrow_mut_data()?,
+        &extra_account_metas,
+    )?;
+    Ok(())
+}
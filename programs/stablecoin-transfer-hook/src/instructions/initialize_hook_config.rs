@@ -0,0 +1,44 @@
+use crate::{state::HookConfig, SEED_HOOK_CONFIG};
+use anchor_lang::prelude::*;
+use solana_stablecoin::{state::Config, ANCHOR_DISCRIMINATOR, SEED_CONFIG_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct InitializeHookConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // the hook is administered by the stablecoin authority
+    #[account(
+        seeds = [SEED_CONFIG_ACCOUNT],
+        bump = stablecoin_config.bump,
+        seeds::program = solana_stablecoin::ID,
+        has_one = authority,
+    )]
+    pub stablecoin_config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + HookConfig::INIT_SPACE,
+        seeds = [SEED_HOOK_CONFIG],
+        bump
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// create the transfer policy config
+/// # Arguments
+/// * `epoch_transfer_limit` - Most an owner may send per epoch, 0 means unlimited
+pub fn process_initialize_hook_config(
+    context: Context<InitializeHookConfig>,
+    epoch_transfer_limit: u64,
+) -> Result<()> {
+    *context.accounts.hook_config = HookConfig {
+        authority: context.accounts.authority.key(),
+        paused: false,
+        epoch_transfer_limit,
+        bump: context.bumps.hook_config,
+    };
+    Ok(())
+}
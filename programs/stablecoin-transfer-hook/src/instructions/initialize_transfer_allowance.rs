@@ -0,0 +1,35 @@
+use crate::{state::TransferAllowance, SEED_TRANSFER_ALLOWANCE};
+use anchor_lang::prelude::*;
+use solana_stablecoin::ANCHOR_DISCRIMINATOR;
+
+#[derive(Accounts)]
+pub struct InitializeTransferAllowance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: any wallet or PDA that owns stablecoin token accounts
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + TransferAllowance::INIT_SPACE,
+        seeds = [SEED_TRANSFER_ALLOWANCE, owner.key().as_ref()],
+        bump
+    )]
+    pub transfer_allowance: Account<'info, TransferAllowance>,
+    pub system_program: Program<'info, System>,
+}
+
+// owners need an allowance account to send the stablecoin while an epoch transfer limit is set
+pub fn process_initialize_transfer_allowance(
+    context: Context<InitializeTransferAllowance>,
+) -> Result<()> {
+    *context.accounts.transfer_allowance = TransferAllowance {
+        owner: context.accounts.owner.key(),
+        epoch: Clock::get()?.epoch,
+        amount_transferred: 0,
+        bump: context.bumps.transfer_allowance,
+    };
+    Ok(())
+}
@@ -0,0 +1,10 @@
+pub mod initialize_extra_account_meta_list;
+pub use initialize_extra_account_meta_list::*;
+pub mod initialize_hook_config;
+pub use initialize_hook_config::*;
+pub mod initialize_transfer_allowance;
+pub use initialize_transfer_allowance::*;
+pub mod transfer_hook;
+pub use transfer_hook::*;
+pub mod update_hook_config;
+pub use update_hook_config::*;
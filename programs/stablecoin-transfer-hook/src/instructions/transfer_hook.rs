@@ -0,0 +1,130 @@
+use crate::{
+    error::TransferHookError,
+    state::{HookConfig, TransferAllowance},
+    SEED_EXTRA_ACCOUNT_METAS, SEED_HOOK_CONFIG, SEED_TRANSFER_ALLOWANCE,
+};
+use anchor_lang::{error::ErrorCode, prelude::*};
+use anchor_spl::token_interface::{
+    spl_token_2022::{
+        extension::{
+            transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::Account as SplTokenAccount,
+    },
+    Mint, TokenAccount,
+};
+use solana_stablecoin::{pda::find_config_address, SEED_BLOCKLIST};
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: transfer authority, the owner or a delegate of the source account
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: validated by seeds, read by Token-2022 to resolve the accounts below
+    #[account(
+        seeds = [SEED_EXTRA_ACCOUNT_METAS, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: only used to derive the blocklist entries
+    #[account(address = solana_stablecoin::ID)]
+    pub stablecoin_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_HOOK_CONFIG],
+        bump = hook_config.bump,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+
+    /// CHECK: only holds data when the source owner is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, source_token.owner.as_ref()],
+        bump,
+        seeds::program = stablecoin_program.key(),
+    )]
+    pub source_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: only holds data when the destination owner is blocklisted
+    #[account(
+        seeds = [SEED_BLOCKLIST, destination_token.owner.as_ref()],
+        bump,
+        seeds::program = stablecoin_program.key(),
+    )]
+    pub destination_blocklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: validated by seeds, only has to exist while an epoch transfer limit is set
+    #[account(
+        mut,
+        seeds = [SEED_TRANSFER_ALLOWANCE, source_token.owner.as_ref()],
+        bump,
+    )]
+    pub source_allowance: UncheckedAccount<'info>,
+}
+
+pub fn process_transfer_hook(context: Context<TransferHook>, amount: u64) -> Result<()> {
+    require_transferring(&context.accounts.source_token)?;
+
+    // forced recoveries are signed by the stablecoin config as permanent delegate and
+    // have to go through even for paused or blocklisted owners
+    if context.accounts.owner.key() == find_config_address().0 {
+        return Ok(());
+    }
+
+    require!(
+        !context.accounts.hook_config.paused,
+        TransferHookError::TransfersPaused
+    );
+    require!(
+        context.accounts.source_blocklist_entry.data_is_empty()
+            && context.accounts.destination_blocklist_entry.data_is_empty(),
+        TransferHookError::Blocklisted
+    );
+
+    let epoch_transfer_limit = context.accounts.hook_config.epoch_transfer_limit;
+    if epoch_transfer_limit > 0 {
+        let epoch = Clock::get()?.epoch;
+        let source_allowance_info = context.accounts.source_allowance.to_account_info();
+        require_keys_eq!(
+            *source_allowance_info.owner,
+            crate::ID,
+            ErrorCode::AccountNotInitialized
+        );
+        let mut source_allowance =
+            TransferAllowance::try_deserialize(&mut &source_allowance_info.try_borrow_data()?[..])?;
+        if source_allowance.epoch != epoch {
+            source_allowance.epoch = epoch;
+            source_allowance.amount_transferred = 0;
+        }
+        source_allowance.amount_transferred = source_allowance
+            .amount_transferred
+            .checked_add(amount)
+            .ok_or(TransferHookError::MathError)?;
+        require!(
+            source_allowance.amount_transferred <= epoch_transfer_limit,
+            TransferHookError::EpochTransferLimitExceeded
+        );
+        source_allowance
+            .try_serialize(&mut &mut source_allowance_info.try_borrow_mut_data()?[..])?;
+    }
+    Ok(())
+}
+
+// Token-2022 sets the flag on the source account for the duration of the hook call,
+// anyone calling the hook directly would not have it set
+fn require_transferring(source_token: &InterfaceAccount<TokenAccount>) -> Result<()> {
+    let source_info = source_token.to_account_info();
+    let data = source_info.try_borrow_data()?;
+    let account = StateWithExtensions::<SplTokenAccount>::unpack(&data)?;
+    let extension = account.get_extension::<TransferHookAccount>()?;
+    require!(
+        bool::from(extension.transferring),
+        TransferHookError::NotTransferring
+    );
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use crate::{state::HookConfig, SEED_HOOK_CONFIG};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateHookConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_HOOK_CONFIG],
+        bump = hook_config.bump,
+        has_one = authority,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+}
+
+pub fn process_update_hook_config(
+    context: Context<UpdateHookConfig>,
+    paused: bool,
+    epoch_transfer_limit: u64,
+) -> Result<()> {
+    let hook_config = &mut context.accounts.hook_config;
+    hook_config.paused = paused;
+    hook_config.epoch_transfer_limit = epoch_transfer_limit;
+    Ok(())
+}
@@ -0,0 +1,49 @@
+pub mod constants;
+pub mod error;
+pub mod instructions;
+pub mod state;
+
+use anchor_lang::prelude::*;
+
+pub use constants::*;
+pub use instructions::*;
+pub use state::*;
+
+declare_id!("F4W8xn5KbfL76c6qfkHGEKYn9R3n9C6TLBZ9L2aWv1wz");
+
+#[program]
+pub mod stablecoin_transfer_hook {
+    use super::*;
+
+    pub fn initialize_hook_config(
+        context: Context<InitializeHookConfig>,
+        epoch_transfer_limit: u64,
+    ) -> Result<()> {
+        process_initialize_hook_config(context, epoch_transfer_limit)
+    }
+
+    pub fn update_hook_config(
+        context: Context<UpdateHookConfig>,
+        paused: bool,
+        epoch_transfer_limit: u64,
+    ) -> Result<()> {
+        process_update_hook_config(context, paused, epoch_transfer_limit)
+    }
+
+    pub fn initialize_extra_account_meta_list(
+        context: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        process_initialize_extra_account_meta_list(context)
+    }
+
+    pub fn initialize_transfer_allowance(
+        context: Context<InitializeTransferAllowance>,
+    ) -> Result<()> {
+        process_initialize_transfer_allowance(context)
+    }
+
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn transfer_hook(context: Context<TransferHook>, amount: u64) -> Result<()> {
+        process_transfer_hook(context, amount)
+    }
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct HookConfig {
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub epoch_transfer_limit: u64, // most an owner may send per epoch, 0 means unlimited
+    pub bump: u8,
+}
@@ -0,0 +1,4 @@
+pub mod hook_config;
+pub use hook_config::*;
+pub mod transfer_allowance;
+pub use transfer_allowance::*;
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct TransferAllowance {
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub amount_transferred: u64, // sent by `owner` during `epoch`
+    pub bump: u8,
+}
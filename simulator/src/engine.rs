@@ -0,0 +1,143 @@
+//! Replays a price path against a population of positions for one set of risk
+//! parameters, with the program's own math deciding every liquidation.
+use crate::prices::PricePoint;
+use anchor_lang::{error::Error, prelude::Pubkey};
+use anyhow::Context;
+use solana_stablecoin::{
+    error::StablecointError,
+    math::{self, OraclePrice},
+    new_config,
+    state::Config,
+};
+
+/// Config with the risk parameters under test, the rest is left at its defaults.
+pub fn config(
+    liquidation_threshold: u64,
+    liquidation_bonus: u64,
+    min_health_factor: u64,
+) -> Config {
+    Config {
+        liquidation_threshold,
+        liquidation_bonus,
+        min_health_factor,
+        ..new_config(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Open,
+    Liquidated,
+    Stuck, // unhealthy, but no liquidation of it passes the program's checks
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    lamport_balance: u64,
+    amount_minted: u64,
+    status: Status,
+}
+
+/// What happened to the population over the whole path.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Report {
+    pub positions: usize,
+    pub liquidations: usize,
+    pub debt_liquidated: u64,
+    pub liquidator_profit: i128, // value of the seized collateral minus the debt burned
+    pub stuck_positions: usize,
+    pub stuck_debt: u64,
+    pub bad_debt: u64, // debt of stuck positions not covered by their collateral
+}
+
+/// `count` positions with `collateral` lamports each, minted at evenly spread
+/// utilizations of what `config` allows at `price`, from 1/count up to the maximum.
+fn population(
+    config: &Config,
+    price: OraclePrice,
+    count: usize,
+    collateral: u64,
+) -> anyhow::Result<Vec<Position>> {
+    // without a minimum the population is spread up to health factor 1
+    let max_debt = math::max_debt(
+        collateral,
+        config.liquidation_threshold,
+        config.min_health_factor.max(1),
+        price,
+    )?;
+    Ok((1..=count)
+        .map(|index| Position {
+            lamport_balance: collateral,
+            amount_minted: (max_debt as u128 * index as u128 / count as u128) as u64,
+            status: Status::Open,
+        })
+        .filter(|position| position.amount_minted > 0)
+        .collect())
+}
+
+/// Runs the path. At every price, each unhealthy position is liquidated in full when
+/// the program would accept it, otherwise it is stuck with its debt for good.
+pub fn simulate(
+    config: &Config,
+    path: &[PricePoint],
+    count: usize,
+    collateral_per_position: u64,
+) -> anyhow::Result<Report> {
+    let first = path.first().context("the price path is empty")?;
+    let mut positions = population(config, first.price, count, collateral_per_position)?;
+    let mut report = Report {
+        positions: positions.len(),
+        ..Report::default()
+    };
+
+    for point in path {
+        for position in positions
+            .iter_mut()
+            .filter(|position| position.status == Status::Open)
+        {
+            let health_factor = math::health_factor(
+                position.lamport_balance,
+                position.amount_minted,
+                config.liquidation_threshold,
+                point.price,
+            )?;
+            if !math::is_liquidatable(health_factor, config.min_health_factor) {
+                continue;
+            }
+            match math::liquidate_position(
+                position.lamport_balance,
+                position.amount_minted,
+                position.amount_minted,
+                config.liquidation_bonus,
+                point.price,
+            ) {
+                Ok(outcome) => {
+                    let seized_value = math::usd_value(outcome.lamports_paid, point.price)?;
+                    report.liquidations += 1;
+                    report.debt_liquidated += position.amount_minted;
+                    report.liquidator_profit +=
+                        seized_value as i128 - position.amount_minted as i128;
+                    position.lamport_balance = outcome.lamport_balance;
+                    position.amount_minted = outcome.amount_minted;
+                    position.status = Status::Liquidated;
+                }
+                Err(error) if error == Error::from(StablecointError::InsufficientCollateral) => {
+                    let collateral_value = math::usd_value(position.lamport_balance, point.price)?;
+                    report.stuck_positions += 1;
+                    report.stuck_debt += position.amount_minted;
+                    report.bad_debt += position.amount_minted.saturating_sub(collateral_value);
+                    position.status = Status::Stuck;
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,4 @@
+//! Backtesting engine behind `stablecoin-sim`, split out of the binary so it can be
+//! tested on its own.
+pub mod engine;
+pub mod prices;
@@ -0,0 +1,98 @@
+//! `stablecoin-sim`: backtest risk parameters against historical SOL/USD prices.
+//!
+//! Every combination of the given thresholds, bonuses and minimum health factors is
+//! replayed over the same price path and population, and reported on one line each.
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use anyhow::{ensure, Result};
+use clap::Parser;
+use solana_stablecoin::{LIQUIDATION_BONUS, LIQUIDATION_THRESHOLD, MIN_HEALTH_FACTOR};
+use solana_stablecoin_simulator::{
+    engine::{config, simulate, Report},
+    prices,
+};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(name = "stablecoin-sim", version, about)]
+struct Cli {
+    /// CSV with `timestamp,price` rows, prices in dollars
+    #[arg(long)]
+    prices: PathBuf,
+    /// Number of simulated positions
+    #[arg(long, default_value_t = 100)]
+    positions: usize,
+    /// SOL deposited in each position
+    #[arg(long, default_value_t = 10.0)]
+    collateral: f64,
+    /// Liquidation thresholds to test, in percent
+    #[arg(long, value_delimiter = ',', default_values_t = [LIQUIDATION_THRESHOLD])]
+    threshold: Vec<u64>,
+    /// Liquidation bonuses to test, in percent
+    #[arg(long, value_delimiter = ',', default_values_t = [LIQUIDATION_BONUS])]
+    bonus: Vec<u64>,
+    /// Minimum health factors to test
+    #[arg(long, value_delimiter = ',', default_values_t = [MIN_HEALTH_FACTOR])]
+    min_health_factor: Vec<u64>,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    ensure!(cli.positions > 0, "--positions must be at least 1");
+    ensure!(cli.collateral > 0.0, "--collateral must be positive");
+    let path = prices::load(&cli.prices)?;
+    let collateral = (cli.collateral * LAMPORTS_PER_SOL as f64) as u64;
+    println!(
+        "{} prices from {} to {}, {} positions of {} SOL",
+        path.len(),
+        path[0].timestamp,
+        path[path.len() - 1].timestamp,
+        cli.positions,
+        cli.collateral
+    );
+    println!(
+        "{:>9} {:>5} {:>6} {:>9} {:>12} {:>15} {:>18} {:>6} {:>15} {:>15}",
+        "threshold",
+        "bonus",
+        "min_hf",
+        "positions",
+        "liquidations",
+        "debt_liquidated",
+        "liquidator_profit",
+        "stuck",
+        "stuck_debt",
+        "bad_debt"
+    );
+    for &threshold in &cli.threshold {
+        for &bonus in &cli.bonus {
+            for &min_health_factor in &cli.min_health_factor {
+                let config = config(threshold, bonus, min_health_factor);
+                let report = simulate(&config, &path, cli.positions, collateral)?;
+                print_row(threshold, bonus, min_health_factor, &report);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_row(threshold: u64, bonus: u64, min_health_factor: u64, report: &Report) {
+    println!(
+        "{:>9} {:>5} {:>6} {:>9} {:>12} {:>15} {:>18} {:>6} {:>15} {:>15}",
+        threshold,
+        bonus,
+        min_health_factor,
+        report.positions,
+        report.liquidations,
+        format_usd(report.debt_liquidated as i128),
+        format_usd(report.liquidator_profit),
+        report.stuck_positions,
+        format_usd(report.stuck_debt as i128),
+        format_usd(report.bad_debt as i128)
+    );
+}
+
+// stablecoin base units as dollars with cents
+fn format_usd(amount: i128) -> String {
+    let cents = amount / 10_000_000;
+    let sign = if cents < 0 { "-" } else { "" };
+    format!("{sign}{}.{:02}", cents.abs() / 100, cents.abs() % 100)
+}
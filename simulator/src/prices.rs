@@ -0,0 +1,52 @@
+use anyhow::{bail, ensure, Context, Result};
+use serde::Deserialize;
+use solana_stablecoin::math::OraclePrice;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PricePoint {
+    pub timestamp: i64,
+    pub price: OraclePrice,
+}
+
+#[derive(Deserialize)]
+struct Row {
+    timestamp: i64,
+    price: String,
+}
+
+/// Reads a `timestamp,price` CSV with a header row, prices in dollars like `142.53`.
+/// Rows are returned in timestamp order.
+pub fn load(path: &Path) -> Result<Vec<PricePoint>> {
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    let mut points = Vec::new();
+    for (line, row) in reader.deserialize::<Row>().enumerate() {
+        // the header is line 1
+        let row = row.with_context(|| format!("{}:{}", path.display(), line + 2))?;
+        let price =
+            parse_price(&row.price).with_context(|| format!("{}:{}", path.display(), line + 2))?;
+        points.push(PricePoint {
+            timestamp: row.timestamp,
+            price,
+        });
+    }
+    ensure!(!points.is_empty(), "{} has no prices", path.display());
+    points.sort_by_key(|point| point.timestamp);
+    Ok(points)
+}
+
+/// Parses a decimal dollar amount exactly, `142.53` becomes `14253 * 10^-2`.
+pub fn parse_price(value: &str) -> Result<OraclePrice> {
+    let value = value.trim();
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let digits = format!("{whole}{fraction}");
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        bail!("invalid price {value:?}");
+    }
+    let price: i64 = digits
+        .parse()
+        .with_context(|| format!("price {value} too large"))?;
+    ensure!(price > 0, "price {value} is not positive");
+    Ok(OraclePrice::new(price, -(fraction.len() as i32)))
+}
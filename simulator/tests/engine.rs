@@ -0,0 +1,76 @@
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_stablecoin::math::OraclePrice;
+use solana_stablecoin_simulator::{
+    engine::{config, simulate},
+    prices::PricePoint,
+};
+
+const USD: u64 = 1_000_000_000;
+
+fn path(dollars: &[i64]) -> Vec<PricePoint> {
+    dollars
+        .iter()
+        .enumerate()
+        .map(|(index, &price)| PricePoint {
+            timestamp: index as i64,
+            price: OraclePrice::new(price, 0),
+        })
+        .collect()
+}
+
+// ten positions of 10 SOL at $100 owe $50, $100, ... up to the $500 maximum
+
+#[test]
+fn steady_price_liquidates_nothing() {
+    let report = simulate(
+        &config(50, 10, 1),
+        &path(&[100, 100]),
+        10,
+        10 * LAMPORTS_PER_SOL,
+    )
+    .unwrap();
+    assert_eq!(report.positions, 10);
+    assert_eq!(report.liquidations, 0);
+    assert_eq!(report.stuck_positions, 0);
+}
+
+#[test]
+fn drop_liquidates_the_positions_it_makes_unhealthy() {
+    let report = simulate(
+        &config(50, 10, 1),
+        &path(&[100, 60]),
+        10,
+        10 * LAMPORTS_PER_SOL,
+    )
+    .unwrap();
+    // at $60 only debts above $300 are unhealthy
+    assert_eq!(report.liquidations, 4);
+    assert_eq!(report.debt_liquidated, (350 + 400 + 450 + 500) * USD);
+    // the bonus, less what the lamport conversion rounds away
+    assert!(report.liquidator_profit > 0);
+    assert!(report.liquidator_profit <= (report.debt_liquidated / 10) as i128);
+    assert_eq!(report.stuck_positions, 0);
+}
+
+#[test]
+fn crash_leaves_positions_stuck_with_bad_debt() {
+    let report = simulate(
+        &config(50, 10, 1),
+        &path(&[100, 10]),
+        10,
+        10 * LAMPORTS_PER_SOL,
+    )
+    .unwrap();
+    // 10 SOL at $10 can't pay $100 plus the bonus, and $50 is still at health factor 1
+    assert_eq!(report.liquidations, 0);
+    assert_eq!(report.stuck_positions, 9);
+    assert_eq!(report.stuck_debt, 2_700 * USD);
+    assert_eq!(report.bad_debt, (2_700 - 9 * 100) * USD);
+}
+
+#[test]
+fn overflow_is_an_error() {
+    let result = simulate(&config(50, 10, 1), &path(&[1_000_000_000]), 1, u64::MAX);
+    assert!(result.is_err());
+    assert!(simulate(&config(50, 10, 1), &[], 1, LAMPORTS_PER_SOL).is_err());
+}
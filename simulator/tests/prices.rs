@@ -0,0 +1,19 @@
+use solana_stablecoin::math::OraclePrice;
+use solana_stablecoin_simulator::prices::parse_price;
+
+#[test]
+fn parses_decimal_dollars_exactly() {
+    assert_eq!(parse_price("142.53").unwrap(), OraclePrice::new(14_253, -2));
+    assert_eq!(parse_price("100").unwrap(), OraclePrice::new(100, 0));
+    assert_eq!(parse_price(" 0.5 ").unwrap(), OraclePrice::new(5, -1));
+    assert_eq!(parse_price("7.").unwrap(), OraclePrice::new(7, 0));
+}
+
+#[test]
+fn rejects_anything_but_a_positive_decimal() {
+    for value in ["", ".", "abc", "-5", "1.2.3", "1e3", "0", "0.00"] {
+        assert!(parse_price(value).is_err(), "accepted {value:?}");
+    }
+    // more digits than an i64 holds
+    assert!(parse_price("99999999999999999999").is_err());
+}
@@ -1,6 +1,7 @@
 // import * as anchor from "@coral-xyz/anchor";
 // import { Program } from "@coral-xyz/anchor";
 // import { SolanaStablecoin } from "../target/types/solana_stablecoin";
+import { StablecoinTransferHook } from "../target/types/stablecoin_transfer_hook";
 // import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
 
 // describe("solana-stablecoin", () => {
@@ -111,6 +112,8 @@ describe("stablecoin", () => {
 
   const program = anchor.workspace
     .SolanaStablecoin as Program<SolanaStablecoin>;
+  const hookProgram = anchor.workspace
+    .StablecoinTransferHook as Program<StablecoinTransferHook>;
 
   /* ---------------- PYTH ---------------- */
   const pythSolanaReceiver = new PythSolanaReceiver({ connection, wallet });
@@ -124,6 +127,8 @@ describe("stablecoin", () => {
 
   /* ---------------- PDAs ---------------- */
 
+  const positionId = new anchor.BN(0);
+
   const [configAccount] = anchor.web3.PublicKey.findProgramAddressSync(
     [Buffer.from("config")],
     program.programId
@@ -135,12 +140,20 @@ describe("stablecoin", () => {
   );
 
   const [collateralAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-    [Buffer.from("collateral"), wallet.publicKey.toBuffer()],
+    [
+      Buffer.from("collateral"),
+      wallet.publicKey.toBuffer(),
+      positionId.toArrayLike(Buffer, "le", 8),
+    ],
     program.programId
   );
 
   const [solAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-    [Buffer.from("sol"), wallet.publicKey.toBuffer()],
+    [
+      Buffer.from("sol"),
+      wallet.publicKey.toBuffer(),
+      positionId.toArrayLike(Buffer, "le", 8),
+    ],
     program.programId
   );
 
@@ -152,14 +165,152 @@ describe("stablecoin", () => {
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
 
+  const [positionMint] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("position_mint"), collateralAccount.toBuffer()],
+    program.programId
+  );
+
+  const positionTokenAccount = getAssociatedTokenAddressSync(
+    positionMint,
+    wallet.publicKey,
+    false,
+    TOKEN_2022_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID
+  );
+
+  const [savingsVault] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("savings_vault")],
+    program.programId
+  );
+
+  /* ---------------- TRANSFER HOOK ---------------- */
+
+  const [extraAccountMetaList] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("extra-account-metas"), mintAccount.toBuffer()],
+    hookProgram.programId
+  );
+
+  const [hookConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("hook_config")],
+    hookProgram.programId
+  );
+
+  const findBlocklistEntry = (owner: anchor.web3.PublicKey) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("blocklist"), owner.toBuffer()],
+      program.programId
+    )[0];
+
+  const findTransferAllowance = (owner: anchor.web3.PublicKey) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("transfer_allowance"), owner.toBuffer()],
+      hookProgram.programId
+    )[0];
+
+  // accounts Token-2022 needs to invoke the hook when `source` sends to `destination`
+  const transferHookAccounts = (
+    source: anchor.web3.PublicKey,
+    destination: anchor.web3.PublicKey
+  ) =>
+    [
+      hookProgram.programId,
+      extraAccountMetaList,
+      program.programId,
+      hookConfig,
+      findBlocklistEntry(source),
+      findBlocklistEntry(destination),
+    ]
+      .map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+      .concat({
+        pubkey: findTransferAllowance(source),
+        isSigner: false,
+        isWritable: true,
+      });
+
+  // recoveries need a key of their own, separate from the admin wallet
+  const recoveryAuthority = anchor.web3.Keypair.generate();
+
   /* ---------------- TESTS ---------------- */
 
   it("Is initialized!", async () => {
-    const tx = await program.methods.initializeConfig().accounts({}).rpc();
+    const tx = await program.methods
+      .initializeConfig(
+        "Programmable Money",
+        "USDS",
+        "https://anchor-stablecoin.vercel.app/metadata.json",
+        recoveryAuthority.publicKey
+      )
+      .accounts({})
+      .rpc();
 
     console.log("Initialize tx:", tx);
   });
 
+  it("Initialize Transfer Hook", async () => {
+    const epochTransferLimit = 0;
+
+    const tx = await hookProgram.methods
+      .initializeHookConfig(new anchor.BN(epochTransferLimit))
+      .accounts({
+        authority: wallet.publicKey,
+      })
+      .rpc();
+
+    console.log("Initialize hook config tx:", tx);
+
+    const listTx = await hookProgram.methods
+      .initializeExtraAccountMetaList()
+      .accounts({
+        authority: wallet.publicKey,
+      })
+      .rpc();
+
+    console.log("Initialize extra account meta list tx:", listTx);
+  });
+
+  it("Initialize Transfer Allowances", async () => {
+    for (const owner of [wallet.publicKey, savingsVault]) {
+      const tx = await hookProgram.methods
+        .initializeTransferAllowance()
+        .accounts({
+          payer: wallet.publicKey,
+          owner,
+        })
+        .rpc();
+
+      console.log("Initialize transfer allowance tx:", tx);
+    }
+  });
+
+  it("Update Token Metadata", async () => {
+    const tx = await program.methods
+      .updateTokenMetadata(
+        "Programmable Money USD",
+        "USDS",
+        "https://anchor-stablecoin.vercel.app/metadata.json"
+      )
+      .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        authority: wallet.publicKey,
+      })
+      .rpc();
+
+    console.log("Update token metadata tx:", tx);
+  });
+
+  it("Open Position", async () => {
+    const tx = await program.methods
+      .openPosition(positionId)
+      .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        positionTokenProgram: TOKEN_2022_PROGRAM_ID,
+        depositor: wallet.publicKey,
+      })
+      .rpc();
+
+    console.log("Open position tx:", tx);
+  });
+
   it("Deposit Collateral and Mint USDS", async () => {
     const amountCollateral = 1_000_000_000;
     const amountToMint = 1_000_000_000;
@@ -167,11 +318,15 @@ describe("stablecoin", () => {
     const tx = await program.methods
       .depositCollateralAndMintTokens(
         new anchor.BN(amountCollateral),
-        new anchor.BN(amountToMint)
+        { exact: { amount: new anchor.BN(amountToMint) } }
       )
       .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
         depositor: wallet.publicKey,
         priceUpdate,
+        collateralAccount,
+        positionTokenAccount,
+        solAccount,
       })
       .rpc();
 
@@ -184,12 +339,16 @@ describe("stablecoin", () => {
 
     const tx = await program.methods
       .redeemCollateralAndBurnTokens(
-        new anchor.BN(amountCollateral),
+        { exact: { amount: new anchor.BN(amountCollateral) } },
         new anchor.BN(amountToBurn)
       )
       .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
         depositor: wallet.publicKey,
         priceUpdate,
+        collateralAccount,
+        positionTokenAccount,
+        solAccount,
       })
       .rpc();
 
@@ -200,6 +359,7 @@ describe("stablecoin", () => {
     const tx = await program.methods
       .updateConfig(new anchor.BN(100))
       .accounts({
+        authority: wallet.publicKey,
         configAccount,
       })
       .rpc();
@@ -213,6 +373,7 @@ describe("stablecoin", () => {
     const tx = await program.methods
       .liquidate(new anchor.BN(amountToBurn))
       .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
         liquidator: wallet.publicKey,
         priceUpdate,
         collateralAccount,
@@ -226,10 +387,117 @@ describe("stablecoin", () => {
     const tx = await program.methods
       .updateConfig(new anchor.BN(1))
       .accounts({
+        authority: wallet.publicKey,
         configAccount,
       })
       .rpc();
 
     console.log("Update config tx:", tx);
   });
+
+  it("Initialize Savings Vault", async () => {
+    const savingsRateBps = 500;
+    const mintBudget = 1_000_000_000;
+
+    const tx = await program.methods
+      .initializeSavingsVault(
+        new anchor.BN(savingsRateBps),
+        new anchor.BN(mintBudget)
+      )
+      .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        authority: wallet.publicKey,
+      })
+      .rpc();
+
+    console.log("Initialize savings vault tx:", tx);
+  });
+
+  it("Deposit Savings", async () => {
+    const amount = 100_000_000;
+
+    const tx = await program.methods
+      .depositSavings(new anchor.BN(amount))
+      .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        depositor: wallet.publicKey,
+        tokenAccount,
+      })
+      .remainingAccounts(transferHookAccounts(wallet.publicKey, savingsVault))
+      .rpc();
+
+    console.log("Deposit savings tx:", tx);
+  });
+
+  it("Drip Savings", async () => {
+    const tx = await program.methods.dripSavings()
+      .accounts({
+        keeper: wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    console.log("Drip savings tx:", tx);
+  });
+
+  it("Withdraw Savings", async () => {
+    const shares = 50_000_000;
+
+    const tx = await program.methods
+      .withdrawSavings(new anchor.BN(shares))
+      .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        depositor: wallet.publicKey,
+        tokenAccount,
+      })
+      .remainingAccounts(transferHookAccounts(savingsVault, wallet.publicKey))
+      .rpc();
+
+    console.log("Withdraw savings tx:", tx);
+  });
+
+  it("Schedule and Cancel Recovery", async () => {
+    const amount = 1_000_000;
+
+    const tx = await program.methods
+      .scheduleRecovery(new anchor.BN(amount), null)
+      .accounts({
+        recoveryAuthority: wallet.publicKey,
+        tokenAccount,
+      })
+      .rpc();
+
+    console.log("Schedule recovery tx:", tx);
+
+    const [recoveryRequest] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("recovery_request"), tokenAccount.toBuffer()],
+      program.programId
+    );
+
+    const cancelTx = await program.methods
+      .cancelRecovery()
+      .accounts({
+        canceller: wallet.publicKey,
+        recoveryRequest,
+      })
+      .rpc();
+
+    console.log("Cancel recovery tx:", cancelTx);
+  });
+
+  it("Close Position", async () => {
+    const tx = await program.methods
+      .closePosition()
+      .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        positionTokenProgram: TOKEN_2022_PROGRAM_ID,
+        depositor: wallet.publicKey,
+        collateralAccount,
+        positionTokenAccount,
+        solAccount,
+      })
+      .rpc();
+
+    console.log("Close position tx:", tx);
+  });
 });